@@ -0,0 +1,359 @@
+//! Element adapters, which allow combinators like `range::With` to apply the semantics of other
+//! field attributes to the values they contain.
+//!
+//! Each marker type here corresponds to the module with the same name. For example, a range of
+//! epoch second strings can be represented like this:
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use serde_field_attributes::{adapter, range};
+//! use std::ops::Range;
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Window {
+//!     #[serde(with = "range::With::<adapter::TimestampStr>")]
+//!     window: Range<DateTime<Utc>>,
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{CheckedMul, PrimInt};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+use std::str::FromStr;
+
+pub use super::bounded_integer::BoundedInteger;
+pub use super::bounded_integer_str::BoundedIntegerStr;
+pub use super::delimited_str::DelimitedStr;
+pub use super::grouped_integer_str::GroupedIntegerStr;
+pub use super::prefixed_integer_str::PrefixedIntegerStr;
+pub use super::radix_str::RadixStr;
+
+pub trait DeserializeAdapter<'de, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+}
+
+pub trait SerializeAdapter<T: ?Sized> {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// A value that is deserialized with the given adapter.
+pub struct Deserializable<A, T> {
+    pub value: T,
+    _adapter: PhantomData<A>,
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> Deserialize<'de> for Deserializable<A, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        A::deserialize(deserializer).map(|value| Self {
+            value,
+            _adapter: PhantomData,
+        })
+    }
+}
+
+/// A reference to a value that is serialized with the given adapter.
+pub struct Serializable<'a, A, T: ?Sized> {
+    value: &'a T,
+    _adapter: PhantomData<A>,
+}
+
+impl<'a, A, T: ?Sized> Serializable<'a, A, T> {
+    pub const fn new(value: &'a T) -> Self {
+        Self {
+            value,
+            _adapter: PhantomData,
+        }
+    }
+}
+
+impl<A: SerializeAdapter<T>, T: ?Sized> Serialize for Serializable<'_, A, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        A::serialize(self.value, serializer)
+    }
+}
+
+/// Use the value type's own `Deserialize` and `Serialize` instances.
+pub struct Plain;
+
+impl<'de, T: Deserialize<'de>> DeserializeAdapter<'de, T> for Plain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+
+impl<T: Serialize + ?Sized> SerializeAdapter<T> for Plain {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+pub struct BigIntStr;
+
+#[cfg(feature = "num-bigint")]
+impl<'de, T: super::bigint_str::BigInteger> DeserializeAdapter<'de, T> for BigIntStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::bigint_str::deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<T: super::bigint_str::BigInteger> SerializeAdapter<T> for BigIntStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::bigint_str::serialize(value, serializer)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+pub struct BigIntOrBigIntStr;
+
+#[cfg(feature = "num-bigint")]
+impl<'de, T: super::bigint_str::BigInteger> DeserializeAdapter<'de, T> for BigIntOrBigIntStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::bigint_or_bigint_str::deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<T: super::bigint_str::BigInteger> SerializeAdapter<T> for BigIntOrBigIntStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::bigint_or_bigint_str::serialize(value, serializer)
+    }
+}
+
+pub struct IntegerStr;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for IntegerStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::integer_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for IntegerStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::integer_str::serialize(value, serializer)
+    }
+}
+
+/// Like [`IntegerStr`], but errors include the `FromStr` error message (e.g. `invalid digit found
+/// in string`).
+pub struct IntegerStrWithReason;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for IntegerStrWithReason
+where
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::integer_str::deserialize_with_reason(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for IntegerStrWithReason {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::integer_str::serialize(value, serializer)
+    }
+}
+
+/// Corresponds to the `integer_str::strict` module.
+pub struct StrictIntegerStr;
+
+impl<'de, T: FromStr + Display> DeserializeAdapter<'de, T> for StrictIntegerStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::integer_str::strict::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for StrictIntegerStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::integer_str::serialize(value, serializer)
+    }
+}
+
+/// Corresponds to the `integer_str::lenient` module.
+pub struct LenientIntegerStr;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for LenientIntegerStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::integer_str::lenient::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for LenientIntegerStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::integer_str::serialize(value, serializer)
+    }
+}
+
+pub struct IntegerOrIntegerStr;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for IntegerOrIntegerStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::integer_or_integer_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for IntegerOrIntegerStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::integer_or_integer_str::serialize(value, serializer)
+    }
+}
+
+pub struct JsSafeInteger;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for JsSafeInteger {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::js_safe_integer::deserialize(deserializer)
+    }
+}
+
+impl<T: PrimInt + Display> SerializeAdapter<T> for JsSafeInteger {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::js_safe_integer::serialize(value, serializer)
+    }
+}
+
+pub struct RepresentedAsAnyScalar;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for RepresentedAsAnyScalar
+where
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::represented_as_any_scalar::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for RepresentedAsAnyScalar {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::represented_as_any_scalar::serialize(value, serializer)
+    }
+}
+
+pub struct RepresentedAsStr;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, T> for RepresentedAsStr
+where
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        super::represented_as_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<T> for RepresentedAsStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        super::represented_as_str::serialize(value, serializer)
+    }
+}
+
+pub struct TimestampStr;
+
+impl<'de> DeserializeAdapter<'de, DateTime<Utc>> for TimestampStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        super::timestamp_str::deserialize(deserializer)
+    }
+}
+
+impl SerializeAdapter<DateTime<Utc>> for TimestampStr {
+    fn serialize<S: Serializer>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::timestamp_str::serialize(value, serializer)
+    }
+}
+
+pub struct TimestampMillisStr;
+
+impl<'de> DeserializeAdapter<'de, DateTime<Utc>> for TimestampMillisStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        super::timestamp_millis_str::deserialize(deserializer)
+    }
+}
+
+impl SerializeAdapter<DateTime<Utc>> for TimestampMillisStr {
+    fn serialize<S: Serializer>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::timestamp_millis_str::serialize(value, serializer)
+    }
+}
+
+/// Corresponds to the `hex_str` module.
+pub type HexStr = RadixStr<16, true>;
+
+pub struct RangeStr;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, Range<T>> for RangeStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Range<T>, D::Error> {
+        super::range_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<Range<T>> for RangeStr {
+    fn serialize<S: Serializer>(value: &Range<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::range_str::serialize(value, serializer)
+    }
+}
+
+pub struct RangeInclusiveStr;
+
+impl<'de, T: FromStr> DeserializeAdapter<'de, RangeInclusive<T>> for RangeInclusiveStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RangeInclusive<T>, D::Error> {
+        super::range_inclusive_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Display> SerializeAdapter<RangeInclusive<T>> for RangeInclusiveStr {
+    fn serialize<S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::range_inclusive_str::serialize(value, serializer)
+    }
+}
+
+pub struct RatioStr;
+
+impl<'de, T: Clone + Integer + FromStr> DeserializeAdapter<'de, Ratio<T>> for RatioStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Ratio<T>, D::Error> {
+        super::ratio_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Clone + Integer + CheckedMul + Display> SerializeAdapter<Ratio<T>> for RatioStr {
+    fn serialize<S: Serializer>(value: &Ratio<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::ratio_str::serialize(value, serializer)
+    }
+}
+
+pub struct RatioOrRatioStr;
+
+impl<'de, T: Clone + Integer + FromStr> DeserializeAdapter<'de, Ratio<T>> for RatioOrRatioStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Ratio<T>, D::Error> {
+        super::ratio_or_ratio_str::deserialize(deserializer)
+    }
+}
+
+impl<T: Clone + Integer + CheckedMul + Display> SerializeAdapter<Ratio<T>> for RatioOrRatioStr {
+    fn serialize<S: Serializer>(value: &Ratio<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::ratio_or_ratio_str::serialize(value, serializer)
+    }
+}
+
+pub struct PercentStr;
+
+impl<'de> DeserializeAdapter<'de, Ratio<i64>> for PercentStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Ratio<i64>, D::Error> {
+        super::percent_str::deserialize(deserializer)
+    }
+}
+
+impl SerializeAdapter<Ratio<i64>> for PercentStr {
+    fn serialize<S: Serializer>(value: &Ratio<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+        super::percent_str::serialize(value, serializer)
+    }
+}
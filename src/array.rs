@@ -0,0 +1,575 @@
+//! Deserialize an array into a collection, applying an element adapter to each element (and the
+//! reverse).
+//!
+//! `Array::<adapter::IntegerStr>` is equivalent to the `integer_str_array` module, and any other
+//! element adapter can be used in the same way (e.g. `Array::<adapter::HexStr>`).
+//!
+//! Array combinators are also adapters, so they can be nested to any depth:
+//!
+//! ```
+//! use serde_field_attributes::{adapter, array::Array};
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Matrix {
+//!     #[serde(with = "Array::<Array<adapter::IntegerStr>>")]
+//!     rows: Vec<Vec<u64>>,
+//! }
+//!
+//! let matrix: Matrix = serde_json::from_str(r#"{"rows":[["1","2"],["3"]]}"#).unwrap();
+//!
+//! assert_eq!(matrix.rows, vec![vec![1, 2], vec![3]]);
+//! ```
+//!
+//! Errors in elements are reported with the index of the element (as an [`ElementError`]).
+//!
+//! [`FixedArray`] is the equivalent for fixed-size arrays (`[T; N]`), which are deserialized from
+//! arrays of exactly `N` elements.
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserializer, Expected, IgnoredAny, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, SerializeTuple, Serializer},
+};
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+
+const EXPECTED: &str = "array";
+const OPTIONAL_EXPECTED: &str = "optional array";
+
+/// The maximum number of bytes to preallocate based on the length reported by the input.
+const MAX_PREALLOCATION: usize = 1024 * 1024;
+
+/// An error in a single element of an array.
+///
+/// Deserializer error types can only be constructed from a message, so the array deserializers
+/// fail with `Error::custom` applied to this error, and the index is only available in the
+/// message (`error at element {index}: {message}`). Use [`crate::tolerant_array::CollectInvalid`]
+/// to get the element errors as values.
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+#[error("error at element {index}: {message}")]
+pub struct ElementError {
+    pub index: usize,
+    pub message: String,
+}
+
+pub struct Array<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> Array<A> {
+    pub fn deserialize<'de, E, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        A: DeserializeAdapter<'de, E>,
+    {
+        struct ArrayVisitor<A, E, T> {
+            _adapter: PhantomData<A>,
+            _element: PhantomData<E>,
+            _target: PhantomData<T>,
+        }
+
+        impl<'de, A: DeserializeAdapter<'de, E>, E, T: FromIterator<E>> Visitor<'de>
+            for ArrayVisitor<A, E, T>
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str(EXPECTED)
+            }
+
+            fn visit_seq<S: SeqAccess<'de>>(self, seq: S) -> Result<Self::Value, S::Error> {
+                collect_seq::<A, E, T, S>(seq, usize::MAX)
+            }
+        }
+
+        deserializer.deserialize_seq(ArrayVisitor::<A, E, T> {
+            _adapter: PhantomData,
+            _element: PhantomData,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<'a, E: 'a, T: 'a, S: Serializer>(
+        values: &'a T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+        A: SerializeAdapter<E>,
+    {
+        let values = values.into_iter();
+        let mut seq = serializer.serialize_seq(exact_len(&values))?;
+
+        for value in values {
+            seq.serialize_element(&Serializable::<A, E>::new(value))?;
+        }
+
+        seq.end()
+    }
+}
+
+impl<'de, A, E, T> DeserializeAdapter<'de, T> for Array<A>
+where
+    A: DeserializeAdapter<'de, E>,
+    T: IntoIterator<Item = E> + FromIterator<E>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A, E, T> SerializeAdapter<T> for Array<A>
+where
+    A: SerializeAdapter<E>,
+    T: IntoIterator<Item = E>,
+    for<'a> &'a T: IntoIterator<Item = &'a E>,
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub struct OptionalArray<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> OptionalArray<A> {
+    pub fn deserialize<'de, E, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, E>,
+    {
+        struct OptionalArrayVisitor<A, E, T> {
+            _adapter: PhantomData<A>,
+            _element: PhantomData<E>,
+            _target: PhantomData<T>,
+        }
+
+        impl<'de, A: DeserializeAdapter<'de, E>, E, T: FromIterator<E>> Visitor<'de>
+            for OptionalArrayVisitor<A, E, T>
+        {
+            type Value = Option<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str(OPTIONAL_EXPECTED)
+            }
+
+            fn visit_none<EE: serde::de::Error>(self) -> Result<Self::Value, EE> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                Array::<A>::deserialize(deserializer).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(OptionalArrayVisitor::<A, E, T> {
+            _adapter: PhantomData,
+            _element: PhantomData,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<'a, E: 'a, T: 'a, S: Serializer>(
+        values: &'a Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+        A: SerializeAdapter<E>,
+    {
+        match values {
+            Some(values) => serializer.serialize_some(&SerializableArray::<A, E, T> {
+                values,
+                _adapter: PhantomData,
+                _element: PhantomData,
+            }),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, A, E, T> DeserializeAdapter<'de, Option<T>> for OptionalArray<A>
+where
+    A: DeserializeAdapter<'de, E>,
+    T: IntoIterator<Item = E> + FromIterator<E>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A, E, T> SerializeAdapter<Option<T>> for OptionalArray<A>
+where
+    A: SerializeAdapter<E>,
+    T: IntoIterator<Item = E>,
+    for<'a> &'a T: IntoIterator<Item = &'a E>,
+{
+    fn serialize<S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+/// A collection that is serialized as an array with the given element adapter.
+struct SerializableArray<'a, A, E, T> {
+    values: &'a T,
+    _adapter: PhantomData<A>,
+    _element: PhantomData<E>,
+}
+
+impl<'a, A: SerializeAdapter<E>, E: 'a, T: 'a> Serialize for SerializableArray<'a, A, E, T>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Array::<A>::serialize(self.values, serializer)
+    }
+}
+
+pub struct FixedArray<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> FixedArray<A> {
+    pub fn deserialize<'de, E, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[E; N], D::Error>
+    where
+        A: DeserializeAdapter<'de, E>,
+    {
+        struct FixedArrayVisitor<A, E, const N: usize> {
+            _adapter: PhantomData<A>,
+            _element: PhantomData<E>,
+        }
+
+        impl<'de, A: DeserializeAdapter<'de, E>, E, const N: usize> Visitor<'de>
+            for FixedArrayVisitor<A, E, N>
+        {
+            type Value = [E; N];
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Expected::fmt(&LengthExpected(N), formatter)
+            }
+
+            fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+                let values = collect_seq::<A, E, Vec<E>, &mut S>(&mut seq, N)?;
+
+                // Stop at the first extra element instead of reading the rest of the input, so the
+                // actual length is unknown.
+                if seq.next_element::<IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::custom(format_args!(
+                        "invalid length: more than {N} elements, expected {}",
+                        &LengthExpected(N) as &dyn Expected
+                    )));
+                }
+
+                <[E; N]>::try_from(values).map_err(|values| {
+                    serde::de::Error::invalid_length(values.len(), &LengthExpected(N))
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            N,
+            FixedArrayVisitor::<A, E, N> {
+                _adapter: PhantomData,
+                _element: PhantomData,
+            },
+        )
+    }
+
+    pub fn serialize<E, const N: usize, S: Serializer>(
+        values: &[E; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<E>,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+
+        for value in values {
+            tuple.serialize_element(&Serializable::<A, E>::new(value))?;
+        }
+
+        tuple.end()
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, E>, E, const N: usize> DeserializeAdapter<'de, [E; N]>
+    for FixedArray<A>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<[E; N], D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<E>, E, const N: usize> SerializeAdapter<[E; N]> for FixedArray<A> {
+    fn serialize<S: Serializer>(value: &[E; N], serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+struct LengthExpected(usize);
+
+impl Expected for LengthExpected {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "array of length {}", self.0)
+    }
+}
+
+/// The length of an iterator, if its size hint is exact.
+pub(crate) fn exact_len<I: Iterator>(iter: &I) -> Option<usize> {
+    match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(lower),
+        _ => None,
+    }
+}
+
+/// An iterator size hint for preallocating from the length reported by the input.
+///
+/// The length is capped, since the input can't be trusted, and isn't an upper bound (which would
+/// also be wrong if we stop at an error).
+pub(crate) fn preallocation_hint<E>(len: Option<usize>) -> (usize, Option<usize>) {
+    let max = MAX_PREALLOCATION / std::mem::size_of::<E>().max(1);
+
+    (len.unwrap_or(0).min(max), None)
+}
+
+/// Collect at most `limit` elements of a sequence, stopping at the first error.
+pub(crate) fn collect_seq<'de, A: DeserializeAdapter<'de, E>, E, T: FromIterator<E>, S>(
+    seq: S,
+    limit: usize,
+) -> Result<T, S::Error>
+where
+    S: SeqAccess<'de>,
+{
+    let mut error = OnceCell::new();
+
+    let result = SeqAccessIterator::<'de, '_, S, A, E> {
+        underlying: seq,
+        index: 0,
+        error: &mut error,
+        _adapter: PhantomData,
+        _element: PhantomData,
+    }
+    .take(limit)
+    .collect();
+
+    error.take().map_or_else(|| Ok(result), |error| Err(error))
+}
+
+struct SeqAccessIterator<'de, 'a, S: SeqAccess<'de>, A, E> {
+    underlying: S,
+    index: usize,
+    error: &'a mut OnceCell<S::Error>,
+    _adapter: PhantomData<A>,
+    _element: PhantomData<E>,
+}
+
+impl<'de, S: SeqAccess<'de>, A: DeserializeAdapter<'de, E>, E> Iterator
+    for SeqAccessIterator<'de, '_, S, A, E>
+{
+    type Item = E;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        preallocation_hint::<E>(self.underlying.size_hint())
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.get().is_some() {
+            None
+        } else {
+            match self.underlying.next_element::<Deserializable<A, E>>() {
+                Ok(Some(value)) => {
+                    self.index += 1;
+                    Some(value.value)
+                }
+                Ok(None) => None,
+                Err(error) => {
+                    let error = serde::de::Error::custom(ElementError {
+                        index: self.index,
+                        message: error.to_string(),
+                    });
+
+                    // We've just checked whether the cell is initialized.
+                    self.error.set(error).unwrap();
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Array, FixedArray, OptionalArray};
+    use crate::adapter::{IntegerStr, Plain, TimestampStr};
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::collections::BTreeSet;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "Array::<IntegerStr>")]
+        ids: BTreeSet<u64>,
+        #[serde(with = "OptionalArray::<TimestampStr>")]
+        timestamps: Option<Vec<DateTime<Utc>>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"ids":["3","1","2"],"timestamps":["1609459200"]}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                ids: [1, 2, 3].into_iter().collect(),
+                timestamps: Some(vec![Utc.timestamp_opt(1_609_459_200, 0).unwrap()]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"ids":["1","2","3"],"timestamps":["1609459200"]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"ids":["1",2],"timestamps":null}"#,
+            r#"{"ids":["1","x"],"timestamps":null}"#,
+            r#"{"ids":[],"timestamps":[1609459200]}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn parse_json_invalid_element_index() {
+        let error =
+            serde_json::from_str::<Test>(r#"{"ids":["1","2","x"],"timestamps":null}"#).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "error at element 2: invalid value: string \"x\", expected integer string at line 1 column 19"
+        );
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Nested {
+        #[serde(with = "Array::<Array<IntegerStr>>")]
+        matrix: Vec<Vec<u64>>,
+        #[serde(with = "Array::<OptionalArray<Array<IntegerStr>>>")]
+        deep: Vec<Option<BTreeSet<Vec<i8>>>>,
+        #[serde(with = "OptionalArray::<FixedArray<IntegerStr>>")]
+        pairs: Option<Vec<[u8; 2]>>,
+    }
+
+    #[test]
+    fn round_trip_nested() {
+        let json =
+            r#"{"matrix":[["1","2"],[],["3"]],"deep":[null,[["-1"],[]]],"pairs":[["1","2"]]}"#;
+        let nested = serde_json::from_str::<Nested>(json).unwrap();
+
+        assert_eq!(
+            nested,
+            Nested {
+                matrix: vec![vec![1, 2], vec![], vec![3]],
+                deep: vec![None, Some(BTreeSet::from([vec![], vec![-1]]))],
+                pairs: Some(vec![[1, 2]]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&nested).unwrap(),
+            r#"{"matrix":[["1","2"],[],["3"]],"deep":[null,[[],["-1"]]],"pairs":[["1","2"]]}"#
+        );
+
+        let bytes = bincode::serialize(&nested).unwrap();
+
+        assert_eq!(bincode::deserialize::<Nested>(&bytes).unwrap(), nested);
+    }
+
+    #[test]
+    fn parse_json_nested_invalid() {
+        let error = serde_json::from_str::<Nested>(
+            r#"{"matrix":[["1"],["2","x"]],"deep":[],"pairs":null}"#,
+        )
+        .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("error at element 1: error at element 1: invalid value: string \"x\""),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn round_trip_bincode() {
+        let test = Test {
+            ids: [1, 2, 3].into_iter().collect(),
+            timestamps: Some(vec![Utc.timestamp_opt(1_609_459_200, 0).unwrap()]),
+        };
+        let bytes = bincode::serialize(&test).unwrap();
+
+        assert_eq!(bincode::deserialize::<Test>(&bytes).unwrap(), test);
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Fixed {
+        #[serde(with = "FixedArray::<IntegerStr>")]
+        point: [i32; 3],
+        #[serde(with = "FixedArray::<Plain>")]
+        pair: [String; 2],
+    }
+
+    #[test]
+    fn round_trip_fixed() {
+        let json = r#"{"point":["1","-2","3"],"pair":["a",""]}"#;
+        let fixed = serde_json::from_str::<Fixed>(json).unwrap();
+
+        assert_eq!(
+            fixed,
+            Fixed {
+                point: [1, -2, 3],
+                pair: ["a".to_string(), String::new()],
+            }
+        );
+        assert_eq!(serde_json::to_string(&fixed).unwrap(), json);
+
+        let bytes = bincode::serialize(&fixed).unwrap();
+
+        assert_eq!(bincode::deserialize::<Fixed>(&bytes).unwrap(), fixed);
+    }
+
+    #[test]
+    fn parse_fixed_invalid_length() {
+        for (json, message) in [
+            (
+                r#"{"point":["1","2"],"pair":["",""]}"#,
+                "invalid length 2, expected array of length 3",
+            ),
+            (
+                r#"{"point":["1","2","3","4"],"pair":["",""]}"#,
+                "invalid length: more than 3 elements, expected array of length 3",
+            ),
+            (
+                r#"{"point":["1","2","3","4","x",{}],"pair":["",""]}"#,
+                "invalid length: more than 3 elements, expected array of length 3",
+            ),
+            (
+                r#"{"point":["1","2","3"],"pair":[]}"#,
+                "invalid length 0, expected array of length 2",
+            ),
+        ] {
+            let error = serde_json::from_str::<Fixed>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Deserialize an integer number of basis points into the fraction it represents (and the
+//! reverse).
+
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::Serializer,
+};
+
+const BASIS_POINTS: i64 = 10_000;
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ratio<i64>, D::Error> {
+    let value = i64::deserialize(deserializer)?;
+
+    Ok(Ratio::new(value, BASIS_POINTS))
+}
+
+pub fn serialize<S: Serializer>(value: &Ratio<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+    let value = value
+        .checked_mul(&Ratio::from_integer(BASIS_POINTS))
+        .filter(Ratio::is_integer)
+        .ok_or_else(|| {
+            serde::ser::Error::custom("i64 ratio cannot be represented as whole basis points")
+        })?;
+
+    serializer.serialize_i64(value.to_integer())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basis_points;
+    use num_rational::Ratio;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "basis_points")]
+        foo: Ratio<i64>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(r#"{ "foo": 1250 }"#).unwrap();
+
+        assert_eq!(test.foo, Ratio::new(1, 8));
+        assert!(serde_json::from_str::<Test>(r#"{ "foo": 12.5 }"#).is_err());
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = Test {
+            foo: Ratio::new(-1, 8),
+        };
+
+        let serialized = serde_json::json!(value).to_string();
+        let deserialized = serde_json::from_str::<Test>(&serialized).unwrap();
+
+        assert_eq!(serialized, r#"{"foo":-1250}"#);
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    fn serialize_fractional_basis_points() {
+        let value = Test {
+            foo: Ratio::new(1, 100_000),
+        };
+
+        assert!(serde_json::to_string(&value).is_err());
+    }
+}
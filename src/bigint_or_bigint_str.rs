@@ -0,0 +1,151 @@
+//! Arbitrary-precision integers represented as either numbers or decimal strings.
+//!
+//! Numbers are accepted from any integer visitor method (including `u128` and `i128` for formats
+//! that support them), as well as from `serde_json` with the `arbitrary_precision` feature enabled,
+//! which makes it possible to read integers of any size from JSON numbers. Values are serialized as
+//! strings. Requires the `num-bigint` feature.
+
+use crate::bigint_str::BigInteger;
+use serde::{
+    de::{Deserializer, MapAccess, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+const EXPECTED: &str = "big integer or big integer string";
+
+// The key `serde_json` uses to represent numbers when `arbitrary_precision` is enabled.
+const SERDE_JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+pub fn deserialize<'de, T: BigInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct BigIntOrBigIntStrVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: BigInteger> Visitor<'de> for BigIntOrBigIntStrVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            super::bigint_str::parse(v)
+                .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            T::from_i64(v).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &EXPECTED))
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            T::from_u64(v).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &EXPECTED))
+        }
+
+        fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            T::from_i128(v)
+                .ok_or_else(|| E::invalid_value(Unexpected::Other(&v.to_string()), &EXPECTED))
+        }
+
+        fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            T::from_u128(v)
+                .ok_or_else(|| E::invalid_value(Unexpected::Other(&v.to_string()), &EXPECTED))
+        }
+
+        fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+            let key = map.next_key::<Cow<'_, str>>()?;
+
+            if key.as_deref() == Some(SERDE_JSON_NUMBER_TOKEN) {
+                let value = map.next_value::<Cow<'_, str>>()?;
+
+                super::bigint_str::parse(&value).ok_or_else(|| {
+                    serde::de::Error::invalid_value(Unexpected::Other(&value), &EXPECTED)
+                })
+            } else {
+                Err(serde::de::Error::invalid_type(Unexpected::Map, &EXPECTED))
+            }
+        }
+    }
+
+    deserializer.deserialize_any(BigIntOrBigIntStrVisitor {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: BigInteger, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SERDE_JSON_NUMBER_TOKEN;
+    use crate::adapter::{BigIntOrBigIntStr, DeserializeAdapter};
+    use num_bigint::{BigInt, BigUint};
+    use serde::de::value::{Error, I128Deserializer, MapDeserializer};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::bigint_or_bigint_str")]
+        signed: BigInt,
+        #[serde(with = "crate::bigint_or_bigint_str")]
+        unsigned: BigUint,
+        #[serde(with = "crate::bigint_or_bigint_str_array")]
+        values: Vec<BigInt>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"signed":-5,"unsigned":"123456789012345678901234567890","values":[18446744073709551615,"-1"]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                signed: BigInt::from(-5),
+                unsigned: "123456789012345678901234567890".parse().unwrap(),
+                values: vec![BigInt::from(u64::MAX), BigInt::from(-1)],
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"signed":"-5","unsigned":"123456789012345678901234567890","values":["18446744073709551615","-1"]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"signed":1.5,"unsigned":"1","values":[]}"#,
+            r#"{"signed":1,"unsigned":-1,"values":[]}"#,
+            r#"{"signed":1,"unsigned":{"value":"1"},"values":[]}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn deserialize_i128() {
+        let value: BigInt =
+            BigIntOrBigIntStr::deserialize(I128Deserializer::<Error>::new(i128::MIN)).unwrap();
+
+        assert_eq!(value, BigInt::from(i128::MIN));
+    }
+
+    #[test]
+    fn deserialize_arbitrary_precision_number() {
+        let digits = "-1234567890123456789012345678901234567890";
+        let deserializer =
+            MapDeserializer::<_, Error>::new(std::iter::once((SERDE_JSON_NUMBER_TOKEN, digits)));
+        let value: BigInt = BigIntOrBigIntStr::deserialize(deserializer).unwrap();
+
+        assert_eq!(value, digits.parse().unwrap());
+    }
+}
@@ -0,0 +1,102 @@
+//! Arbitrary-precision integers (`BigInt` and `BigUint`) represented as decimal strings.
+//!
+//! Only a leading `-` and decimal digits are accepted (not the `+` sign or `_` separators that
+//! `num-bigint`'s own parsing allows). Requires the `num-bigint` feature.
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::{FromPrimitive, Num};
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+const EXPECTED: &str = "big integer string";
+
+/// The arbitrary-precision integer types provided by `num-bigint`.
+pub trait BigInteger: Num + FromPrimitive + Display {}
+
+impl BigInteger for BigInt {}
+impl BigInteger for BigUint {}
+
+pub fn deserialize<'de, T: BigInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct BigIntStrVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<T: BigInteger> Visitor<'_> for BigIntStrVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            parse(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+    }
+
+    deserializer.deserialize_str(BigIntStrVisitor {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: BigInteger, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+pub(crate) fn parse<T: BigInteger>(value: &str) -> Option<T> {
+    let (negative, digits) = crate::radix_str::split_sign(value);
+
+    crate::radix_str::parse_digits(negative, digits, 10).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, BigUint};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::bigint_str")]
+        signed: BigInt,
+        #[serde(with = "crate::optional_bigint_str")]
+        unsigned: Option<BigUint>,
+        #[serde(with = "crate::bigint_str_array")]
+        values: Vec<BigInt>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"signed":"-123456789012345678901234567890","unsigned":"340282366920938463463374607431768211456","values":["0","-1"]}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                signed: "-123456789012345678901234567890".parse().unwrap(),
+                unsigned: Some(BigUint::from(u128::MAX) + 1u32),
+                values: vec![BigInt::from(0), BigInt::from(-1)],
+            }
+        );
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"signed":"+1","unsigned":null,"values":[]}"#,
+            r#"{"signed":"1_000","unsigned":null,"values":[]}"#,
+            r#"{"signed":"","unsigned":null,"values":[]}"#,
+            r#"{"signed":1,"unsigned":null,"values":[]}"#,
+            r#"{"signed":"1","unsigned":"-1","values":[]}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+}
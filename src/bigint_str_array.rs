@@ -0,0 +1,22 @@
+//! Deserialize an array of decimal strings into a collection of arbitrary-precision integers (and
+//! the reverse).
+
+use crate::{adapter::BigIntStr, array::Array, bigint_str::BigInteger};
+use serde::{de::Deserializer, ser::Serializer};
+use std::iter::FromIterator;
+
+pub fn deserialize<'de, E: BigInteger, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Array::<BigIntStr>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: BigInteger + 'a, T: 'a, S: Serializer>(
+    values: &'a T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    Array::<BigIntStr>::serialize(values, serializer)
+}
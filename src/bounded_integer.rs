@@ -0,0 +1,110 @@
+//! Integers that must fall within inclusive bounds (e.g. `BoundedInteger::<1, 100>`).
+//!
+//! This is the numeric counterpart of `bounded_integer_str`, and supports the same types.
+
+use crate::adapter::{DeserializeAdapter, SerializeAdapter};
+use crate::bounded_integer_str::{Bounds, ToI128, check_bounds};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+pub struct BoundedInteger<const MIN: i128, const MAX: i128>;
+
+impl<const MIN: i128, const MAX: i128> BoundedInteger<MIN, MAX> {
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "lower bound must not exceed upper bound");
+
+    pub fn deserialize<'de, T: Deserialize<'de> + ToI128, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let () = Self::VALID_BOUNDS;
+        let value = T::deserialize(deserializer)?;
+
+        check_bounds(
+            value,
+            &Bounds {
+                min: MIN,
+                max: MAX,
+                kind: "integer",
+            },
+        )
+    }
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+}
+
+impl<'de, const MIN: i128, const MAX: i128, T: Deserialize<'de> + ToI128> DeserializeAdapter<'de, T>
+    for BoundedInteger<MIN, MAX>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, T: Serialize> SerializeAdapter<T>
+    for BoundedInteger<MIN, MAX>
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedInteger;
+    use crate::array::Array;
+    use std::num::NonZeroU32;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "BoundedInteger::<1, 100>")]
+        page_size: NonZeroU32,
+        #[serde(with = "Array::<BoundedInteger<0, 9>>")]
+        digits: Vec<u8>,
+        #[serde(with = "BoundedInteger::<{ i64::MIN as i128 }, 0>")]
+        non_positive: i64,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"page_size":100,"digits":[0,9],"non_positive":-9223372036854775808}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                page_size: NonZeroU32::new(100).unwrap(),
+                digits: vec![0, 9],
+                non_positive: i64::MIN,
+            }
+        );
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"page_size":101,"digits":[],"non_positive":0}"#,
+                "invalid value: integer `101`, expected integer between 1 and 100 (inclusive)",
+            ),
+            (
+                r#"{"page_size":1,"digits":[10],"non_positive":0}"#,
+                "error at element 0: invalid value: integer `10`, expected integer between 0 and 9 (inclusive)",
+            ),
+            (
+                r#"{"page_size":1,"digits":[],"non_positive":1}"#,
+                "invalid value: integer `1`, expected integer between -9223372036854775808 and 0 (inclusive)",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
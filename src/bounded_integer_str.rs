@@ -0,0 +1,222 @@
+//! Integer strings that must fall within inclusive bounds (e.g. `BoundedIntegerStr::<1, 65535>`
+//! for port numbers).
+//!
+//! Parsing is the same as in `integer_str`, and values outside the bounds are rejected with an
+//! error that names them. Any primitive integer type is supported, as well as the `NonZero`
+//! integer types (which also reject zero):
+//!
+//! ```
+//! use serde_field_attributes::{bounded_integer::BoundedInteger, bounded_integer_str::BoundedIntegerStr};
+//! use std::num::NonZeroU32;
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Query {
+//!     #[serde(with = "BoundedIntegerStr::<1, 65535>")]
+//!     port: u16,
+//!     #[serde(with = "BoundedInteger::<1, 100>")]
+//!     page_size: NonZeroU32,
+//! }
+//! ```
+
+use crate::adapter::{DeserializeAdapter, SerializeAdapter};
+use serde::{
+    de::{Deserializer, Expected, Unexpected},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use std::str::FromStr;
+
+/// Integer types whose values can be checked against `i128` bounds.
+pub trait ToI128 {
+    /// Return `None` if the value cannot be represented as an `i128`.
+    fn to_i128(&self) -> Option<i128>;
+}
+
+macro_rules! impl_to_i128 {
+    ($($ty:ty),*) => {
+        $(
+            impl ToI128 for $ty {
+                fn to_i128(&self) -> Option<i128> {
+                    i128::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_to_i128_non_zero {
+    ($($ty:ty),*) => {
+        $(
+            impl ToI128 for $ty {
+                fn to_i128(&self) -> Option<i128> {
+                    self.get().to_i128()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_i128!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_to_i128_non_zero!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+pub struct BoundedIntegerStr<const MIN: i128, const MAX: i128>;
+
+impl<const MIN: i128, const MAX: i128> BoundedIntegerStr<MIN, MAX> {
+    const VALID_BOUNDS: () = assert!(MIN <= MAX, "lower bound must not exceed upper bound");
+
+    pub fn deserialize<'de, T: FromStr + ToI128, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let () = Self::VALID_BOUNDS;
+        let value = super::integer_str::deserialize(deserializer)?;
+
+        check_bounds(
+            value,
+            &Bounds {
+                min: MIN,
+                max: MAX,
+                kind: "integer string",
+            },
+        )
+    }
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::integer_str::serialize(value, serializer)
+    }
+}
+
+impl<'de, const MIN: i128, const MAX: i128, T: FromStr + ToI128> DeserializeAdapter<'de, T>
+    for BoundedIntegerStr<MIN, MAX>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, T: Display> SerializeAdapter<T>
+    for BoundedIntegerStr<MIN, MAX>
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+/// The expected value for a bounded integer.
+pub(crate) struct Bounds {
+    pub min: i128,
+    pub max: i128,
+    pub kind: &'static str,
+}
+
+impl Expected for Bounds {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            formatter,
+            "{} between {} and {} (inclusive)",
+            self.kind, self.min, self.max
+        )
+    }
+}
+
+pub(crate) fn check_bounds<T: ToI128, E: serde::de::Error>(
+    value: T,
+    bounds: &Bounds,
+) -> Result<T, E> {
+    match value.to_i128() {
+        Some(as_i128) if (bounds.min..=bounds.max).contains(&as_i128) => Ok(value),
+        Some(as_i128) => Err(i64::try_from(as_i128).map_or_else(
+            |_| {
+                u64::try_from(as_i128).map_or_else(
+                    |_| E::invalid_value(Unexpected::Other(&as_i128.to_string()), bounds),
+                    |unsigned| E::invalid_value(Unexpected::Unsigned(unsigned), bounds),
+                )
+            },
+            |signed| E::invalid_value(Unexpected::Signed(signed), bounds),
+        )),
+        None => Err(E::invalid_value(
+            Unexpected::Other("integer outside of the 128-bit signed range"),
+            bounds,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedIntegerStr;
+    use crate::optional::Optional;
+    use std::num::NonZeroU64;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "BoundedIntegerStr::<1, 65535>")]
+        port: u16,
+        #[serde(with = "BoundedIntegerStr::<-100, 100>")]
+        percentage: i32,
+        #[serde(with = "Optional::<BoundedIntegerStr<1, 1000>>")]
+        page_size: Option<NonZeroU64>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"port":"65535","percentage":"-100","page_size":"1000"}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                port: 65535,
+                percentage: -100,
+                page_size: NonZeroU64::new(1000),
+            }
+        );
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"port":"0","percentage":"0","page_size":null}"#,
+                "invalid value: integer `0`, expected integer string between 1 and 65535 (inclusive)",
+            ),
+            (
+                r#"{"port":"1","percentage":"101","page_size":null}"#,
+                "invalid value: integer `101`, expected integer string between -100 and 100 (inclusive)",
+            ),
+            (
+                r#"{"port":"1","percentage":"0","page_size":"1001"}"#,
+                "invalid value: integer `1001`, expected integer string between 1 and 1000 (inclusive)",
+            ),
+            (
+                r#"{"port":"1","percentage":"0","page_size":"0"}"#,
+                "invalid value: string \"0\", expected integer string",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Deserialize a delimited string (e.g. `"1,2,3"`, with `""` for an empty collection) into a
+//! collection, parsing each element with `FromStr` (and the reverse).
+
+use crate::{
+    adapter::{DeserializeAdapter, SerializeAdapter},
+    array::ElementError,
+};
+use serde::{
+    de::{Deserializer, Expected, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::cell::OnceCell;
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct DelimitedStr<const DELIMITER: char>;
+
+pub type CommaDelimited = DelimitedStr<','>;
+pub type SpaceDelimited = DelimitedStr<' '>;
+pub type SemicolonDelimited = DelimitedStr<';'>;
+pub type PipeDelimited = DelimitedStr<'|'>;
+
+impl<const DELIMITER: char> DelimitedStr<DELIMITER> {
+    pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        E::Err: Display,
+    {
+        deserializer.deserialize_str(DelimitedStrVisitor::<E, T> {
+            delimiter: DELIMITER,
+            _element: PhantomData,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<'a, E: Display + 'a, T: 'a, S: Serializer>(
+        values: &'a T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+    {
+        let mut result = String::new();
+        let mut count = 0;
+
+        for (i, value) in values.into_iter().enumerate() {
+            let value = value.to_string();
+
+            if value.contains(DELIMITER) {
+                return Err(serde::ser::Error::custom(format!(
+                    "element {value:?} contains the delimiter {DELIMITER:?}"
+                )));
+            }
+
+            if i > 0 {
+                result.push(DELIMITER);
+            }
+
+            result.push_str(&value);
+            count += 1;
+        }
+
+        // The empty string represents an empty collection.
+        if count == 1 && result.is_empty() {
+            return Err(serde::ser::Error::custom(
+                "a single empty element can't be represented as a delimited string",
+            ));
+        }
+
+        serializer.serialize_str(&result)
+    }
+}
+
+impl<'de, const DELIMITER: char, E: FromStr, T: IntoIterator<Item = E> + FromIterator<E>>
+    DeserializeAdapter<'de, T> for DelimitedStr<DELIMITER>
+where
+    E::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<const DELIMITER: char, E: Display, T: IntoIterator<Item = E>> SerializeAdapter<T>
+    for DelimitedStr<DELIMITER>
+where
+    for<'a> &'a T: IntoIterator<Item = &'a E>,
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub struct OptionalDelimitedStr<const DELIMITER: char>;
+
+impl<const DELIMITER: char> OptionalDelimitedStr<DELIMITER> {
+    pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error>
+    where
+        E::Err: Display,
+    {
+        struct DelimitedStrOptVisitor<E, T> {
+            delimiter: char,
+            _element: PhantomData<E>,
+            _target: PhantomData<T>,
+        }
+
+        impl<'de, E: FromStr, T: FromIterator<E>> Visitor<'de> for DelimitedStrOptVisitor<E, T>
+        where
+            E::Err: Display,
+        {
+            type Value = Option<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "optional {:?}-delimited string", self.delimiter)
+            }
+
+            fn visit_none<EE: serde::de::Error>(self) -> Result<Self::Value, EE> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                deserializer
+                    .deserialize_str(DelimitedStrVisitor::<E, T> {
+                        delimiter: self.delimiter,
+                        _element: PhantomData,
+                        _target: PhantomData,
+                    })
+                    .map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(DelimitedStrOptVisitor::<E, T> {
+            delimiter: DELIMITER,
+            _element: PhantomData,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<'a, E: Display + 'a, T: 'a, S: Serializer>(
+        values: &'a Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+    {
+        match values {
+            Some(values) => DelimitedStr::<DELIMITER>::serialize(values, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error>
+where
+    E::Err: Display,
+{
+    CommaDelimited::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: Display + 'a, T: 'a, S: Serializer>(
+    values: &'a T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    CommaDelimited::serialize(values, serializer)
+}
+
+struct ElementExpected<'a, R> {
+    delimiter: char,
+    reason: &'a R,
+}
+
+impl<R: Display> Expected for ElementExpected<'_, R> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            formatter,
+            "element of {:?}-delimited string ({})",
+            self.delimiter, self.reason
+        )
+    }
+}
+
+struct DelimitedStrVisitor<E, T> {
+    delimiter: char,
+    _element: PhantomData<E>,
+    _target: PhantomData<T>,
+}
+
+impl<E: FromStr, T: FromIterator<E>> Visitor<'_> for DelimitedStrVisitor<E, T>
+where
+    E::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{:?}-delimited string", self.delimiter)
+    }
+
+    fn visit_str<EE: serde::de::Error>(self, v: &str) -> Result<Self::Value, EE> {
+        if v.is_empty() {
+            return Ok(std::iter::empty().collect());
+        }
+
+        let error = OnceCell::new();
+
+        let result = v
+            .split(self.delimiter)
+            .enumerate()
+            .map_while(|(index, element)| {
+                element.parse().map_or_else(
+                    |reason| {
+                        let expected = ElementExpected {
+                            delimiter: self.delimiter,
+                            reason: &reason,
+                        };
+                        let message = <serde::de::value::Error as serde::de::Error>::invalid_value(
+                            Unexpected::Str(element),
+                            &expected,
+                        )
+                        .to_string();
+
+                        // The iteration stops at the first error, so the cell is uninitialized.
+                        let _ = error.set(EE::custom(ElementError { index, message }));
+                        None
+                    },
+                    Some,
+                )
+            })
+            .collect();
+
+        error
+            .into_inner()
+            .map_or_else(|| Ok(result), |error| Err(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OptionalDelimitedStr, PipeDelimited, SpaceDelimited};
+    use std::collections::BTreeSet;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::delimited_str")]
+        ids: Vec<u64>,
+        #[serde(with = "SpaceDelimited")]
+        tags: BTreeSet<String>,
+        #[serde(with = "PipeDelimited")]
+        empty: Vec<i32>,
+        #[serde(with = "OptionalDelimitedStr::<';'>")]
+        optional: Option<Vec<i8>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"ids":"3,1,2","tags":"b a","empty":"","optional":"-1;1"}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                ids: vec![3, 1, 2],
+                tags: BTreeSet::from(["a".to_string(), "b".to_string()]),
+                empty: vec![],
+                optional: Some(vec![-1, 1]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"ids":"3,1,2","tags":"a b","empty":"","optional":"-1;1"}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"ids":"1,,2","tags":"","empty":"","optional":null}"#,
+                "error at element 1: invalid value: string \"\", expected element of ','-delimited string (cannot parse integer from empty string)",
+            ),
+            (
+                r#"{"ids":"1, 2","tags":"","empty":"","optional":null}"#,
+                "error at element 1: invalid value: string \" 2\", expected element of ','-delimited string (invalid digit found in string)",
+            ),
+            (
+                r#"{"ids":"","tags":"","empty":"","optional":"1;x"}"#,
+                "error at element 1: invalid value: string \"x\", expected element of ';'-delimited string (invalid digit found in string)",
+            ),
+            (
+                r#"{"ids":[1],"tags":"","empty":"","optional":null}"#,
+                "invalid type: sequence, expected ','-delimited string",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+
+    #[test]
+    fn serialize_json_invalid() {
+        let test = Test {
+            ids: vec![],
+            tags: BTreeSet::from(["a b".to_string()]),
+            empty: vec![],
+            optional: None,
+        };
+
+        assert!(serde_json::to_string(&test).is_err());
+
+        let test = Test {
+            ids: vec![],
+            tags: BTreeSet::from([String::new()]),
+            empty: vec![],
+            optional: None,
+        };
+
+        assert!(serde_json::to_string(&test).is_err());
+    }
+}
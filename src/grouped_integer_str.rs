@@ -0,0 +1,240 @@
+//! Integers represented as strings with digit grouping separators (e.g. `"1,234,567"`).
+//!
+//! Grouped input must use groups of exactly three digits after a leading group of one to three
+//! digits, and input without any separators is also accepted. The module-level functions use `,`
+//! as the separator and serialize with grouping. Other separators can be used with
+//! [`GroupedIntegerStr`] or one of the provided aliases, and the second parameter disables grouping
+//! on serialization:
+//!
+//! ```
+//! use serde_field_attributes::grouped_integer_str::{GroupedIntegerStr, SpaceGrouped};
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Report {
+//!     #[serde(with = "SpaceGrouped")]
+//!     population: u64,
+//!     #[serde(with = "GroupedIntegerStr::<'.', false>")]
+//!     revenue: i64,
+//! }
+//! ```
+
+use crate::adapter::{DeserializeAdapter, SerializeAdapter};
+use serde::{
+    de::{Deserializer, Expected, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+pub struct GroupedIntegerStr<const SEPARATOR: char, const GROUP_ON_SERIALIZE: bool = true>;
+
+pub type CommaGrouped = GroupedIntegerStr<','>;
+pub type DotGrouped = GroupedIntegerStr<'.'>;
+pub type SpaceGrouped = GroupedIntegerStr<' '>;
+pub type ApostropheGrouped = GroupedIntegerStr<'\''>;
+
+impl<const SEPARATOR: char, const GROUP_ON_SERIALIZE: bool>
+    GroupedIntegerStr<SEPARATOR, GROUP_ON_SERIALIZE>
+{
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        struct GroupedIntegerStrVisitor<T> {
+            separator: char,
+            _target: PhantomData<T>,
+        }
+
+        impl<T: FromStr> Visitor<'_> for GroupedIntegerStrVisitor<T> {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Expected::fmt(&GroupedExpected(self.separator), formatter)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                strip_separators(v, self.separator)
+                    .and_then(|stripped| stripped.parse().ok())
+                    .ok_or_else(|| {
+                        E::invalid_value(Unexpected::Str(v), &GroupedExpected(self.separator))
+                    })
+            }
+        }
+
+        deserializer.deserialize_str(GroupedIntegerStrVisitor {
+            separator: SEPARATOR,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = value.to_string();
+
+        if GROUP_ON_SERIALIZE {
+            serializer.serialize_str(&insert_separators(&value, SEPARATOR))
+        } else {
+            serializer.serialize_str(&value)
+        }
+    }
+}
+
+impl<'de, const SEPARATOR: char, const GROUP_ON_SERIALIZE: bool, T: FromStr>
+    DeserializeAdapter<'de, T> for GroupedIntegerStr<SEPARATOR, GROUP_ON_SERIALIZE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<const SEPARATOR: char, const GROUP_ON_SERIALIZE: bool, T: Display> SerializeAdapter<T>
+    for GroupedIntegerStr<SEPARATOR, GROUP_ON_SERIALIZE>
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    CommaGrouped::deserialize(deserializer)
+}
+
+pub fn serialize<T: Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    CommaGrouped::serialize(value, serializer)
+}
+
+struct GroupedExpected(char);
+
+impl Expected for GroupedExpected {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "integer string grouped with {:?}", self.0)
+    }
+}
+
+/// Remove grouping separators, validating the group sizes.
+fn strip_separators(value: &str, separator: char) -> Option<Cow<'_, str>> {
+    let (sign, digits) = value
+        .strip_prefix('-')
+        .map_or(("", value), |rest| ("-", rest));
+
+    if !digits.contains(separator) {
+        return Some(Cow::Borrowed(value));
+    }
+
+    let is_digits = |group: &str| group.bytes().all(|byte| byte.is_ascii_digit());
+    let mut groups = digits.split(separator);
+    let first = groups.next()?;
+
+    if first.is_empty() || first.len() > 3 || first.starts_with('0') || !is_digits(first) {
+        return None;
+    }
+
+    let mut result = String::with_capacity(value.len());
+    result.push_str(sign);
+    result.push_str(first);
+
+    for group in groups {
+        if group.len() != 3 || !is_digits(group) {
+            return None;
+        }
+
+        result.push_str(group);
+    }
+
+    Some(Cow::Owned(result))
+}
+
+/// Insert grouping separators into a formatted integer (which is returned as-is if it isn't a
+/// plain sequence of digits with an optional sign).
+fn insert_separators(value: &str, separator: char) -> String {
+    let (sign, digits) = value
+        .strip_prefix('-')
+        .map_or(("", value), |rest| ("-", rest));
+
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return value.to_string();
+    }
+
+    let mut result = String::with_capacity(value.len() + digits.len() / 3 * separator.len_utf8());
+    result.push_str(sign);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            result.push(separator);
+        }
+
+        result.push(digit);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DotGrouped, GroupedIntegerStr, SpaceGrouped};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::grouped_integer_str")]
+        comma: i64,
+        #[serde(with = "SpaceGrouped")]
+        space: u64,
+        #[serde(with = "DotGrouped")]
+        dot: u32,
+        #[serde(with = "GroupedIntegerStr::<'\\u{202f}', false>")]
+        narrow: u64,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"comma":"-1,234,567","space":"1 000","dot":"999","narrow":"12 345"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                comma: -1_234_567,
+                space: 1000,
+                dot: 999,
+                narrow: 12345,
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"comma":"-1,234,567","space":"1 000","dot":"999","narrow":"12345"}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_ungrouped() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"comma":"1234567","space":"1000","dot":"1234","narrow":"0"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(test.comma, 1_234_567);
+        assert_eq!(test.dot, 1234);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for comma in [
+            "1,23", "1,2345", ",123", "1234,567", "0,123", "1,,234", "1,234,", "1.234", "1,a23",
+        ] {
+            let json = format!(r#"{{"comma":"{comma}","space":"0","dot":"0","narrow":"0"}}"#);
+            let error = serde_json::from_str::<Test>(&json).unwrap_err();
+
+            assert!(
+                error
+                    .to_string()
+                    .contains("expected integer string grouped with ','"),
+                "{error}"
+            );
+        }
+    }
+}
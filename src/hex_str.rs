@@ -0,0 +1,66 @@
+//! Integers represented as hexadecimal strings (e.g. `"0x1f"`).
+//!
+//! Both `"0x1f"` and `"1f"` are accepted, and values are serialized with a lower-case `0x` prefix.
+//! Other conventions are available via `radix_str::RadixStr` (e.g. `RadixStr::<16, false, true>`
+//! for `"1F"`).
+
+use crate::adapter::HexStr;
+use num_traits::{Num, PrimInt};
+use serde::{de::Deserializer, ser::Serializer};
+
+pub fn deserialize<'de, T: Num, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    HexStr::deserialize(deserializer)
+}
+
+pub fn serialize<T: PrimInt, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    HexStr::serialize(value, serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::hex_str")]
+        value: u64,
+        #[serde(with = "crate::optional_hex_str")]
+        optional: Option<i32>,
+        #[serde(with = "crate::hex_str_array")]
+        values: Vec<u8>,
+        #[serde(with = "crate::optional_hex_str_array")]
+        optional_values: Option<Vec<u16>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"value":"1F","optional":"-0x10","values":["0xff","0X0a"],"optional_values":null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                value: 31,
+                optional: Some(-16),
+                values: vec![255, 10],
+                optional_values: None,
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"value":"0x1f","optional":"-0x10","values":["0xff","0xa"],"optional_values":null}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_overflow() {
+        let error = serde_json::from_str::<Test>(
+            r#"{"value":"0","optional":null,"values":["0x100"],"optional_values":null}"#,
+        )
+        .unwrap_err();
+
+        assert!(error.to_string().starts_with(
+            "error at element 0: invalid value: string \"0x100\", expected base-16 integer string within the range of the target type"
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+//! Deserialize an array of hexadecimal strings into a collection of integers (and the reverse).
+
+use crate::{adapter::HexStr, array::Array};
+use num_traits::{Num, PrimInt};
+use serde::{de::Deserializer, ser::Serializer};
+use std::iter::FromIterator;
+
+pub fn deserialize<'de, E: Num, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Array::<HexStr>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: PrimInt + 'a, T: 'a, S: Serializer>(
+    values: &'a T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    Array::<HexStr>::serialize(values, serializer)
+}
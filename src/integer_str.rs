@@ -1,7 +1,8 @@
 use serde::{
-    de::{Deserializer, Unexpected, Visitor},
+    de::{Deserializer, Expected, Unexpected, Visitor},
     ser::Serializer,
 };
+use std::fmt::Display;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
@@ -41,3 +42,206 @@ pub fn serialize<T: std::fmt::Display, S: Serializer>(
 ) -> Result<S::Ok, S::Error> {
     serializer.serialize_str(&value.to_string())
 }
+
+/// Accept only canonical integer strings (no `+` sign, leading zeros or whitespace), which are
+/// exactly the strings that round-trip through `FromStr` and `Display`.
+pub mod strict {
+    use serde::de::Deserializer;
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    const EXPECTED: &str = "canonical integer string";
+
+    pub fn deserialize<'de, T: FromStr + Display, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        super::deserialize_with(deserializer, parse_strict, EXPECTED)
+    }
+
+    pub(crate) fn parse_strict<T: FromStr + Display>(value: &str) -> Option<T> {
+        value
+            .parse::<T>()
+            .ok()
+            .filter(|parsed| parsed.to_string() == value)
+    }
+}
+
+/// Trim surrounding whitespace and accept a `+` sign and a fractional part consisting of zeros
+/// (e.g. `" +5.0 "`).
+pub mod lenient {
+    use serde::de::Deserializer;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        super::deserialize_with(deserializer, parse_lenient, super::EXPECTED)
+    }
+
+    pub(crate) fn parse_lenient<T: FromStr>(value: &str) -> Option<T> {
+        let value = value.trim();
+
+        // A `+` sign can't be followed by another sign.
+        let value = match value.strip_prefix('+') {
+            Some(rest) if rest.starts_with(['+', '-']) => return None,
+            Some(rest) => rest,
+            None => value,
+        };
+
+        let integer = match value.split_once('.') {
+            Some((integer, fraction))
+                if !fraction.is_empty() && fraction.bytes().all(|byte| byte == b'0') =>
+            {
+                integer
+            }
+            Some(_) => return None,
+            None => value,
+        };
+
+        integer.parse().ok()
+    }
+}
+
+fn deserialize_with<'de, T, D: Deserializer<'de>>(
+    deserializer: D,
+    parse: fn(&str) -> Option<T>,
+    expected: &'static str,
+) -> Result<T, D::Error> {
+    struct ParseVisitor<T> {
+        parse: fn(&str) -> Option<T>,
+        expected: &'static str,
+    }
+
+    impl<T> Visitor<'_> for ParseVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(self.expected)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            (self.parse)(v)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &self.expected))
+        }
+    }
+
+    deserializer.deserialize_str(ParseVisitor { parse, expected })
+}
+
+/// Like `deserialize`, but include the `FromStr` error message in the error.
+pub(crate) fn deserialize_with_reason<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error>
+where
+    T::Err: Display,
+{
+    struct ReasonVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<T: FromStr> Visitor<'_> for ReasonVisitor<T>
+    where
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse::<Self::Value>().map_err(|error| {
+                serde::de::Error::invalid_value(Unexpected::Str(v), &ReasonExpected(&error))
+            })
+        }
+    }
+
+    deserializer.deserialize_str(ReasonVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+struct ReasonExpected<'a, R>(&'a R);
+
+impl<R: Display> Expected for ReasonExpected<'_, R> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{EXPECTED} ({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::integer_str::strict")]
+        strict: i64,
+        #[serde(with = "crate::integer_str::lenient")]
+        lenient: u32,
+        #[serde(with = "crate::optional_integer_str::strict")]
+        optional_strict: Option<u8>,
+        #[serde(with = "crate::integer_str_array::lenient")]
+        lenient_values: Vec<i16>,
+        #[serde(with = "crate::optional_integer_str_array::strict")]
+        optional_strict_values: Option<Vec<u64>>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"strict":"-5","lenient":" +5.00 ","optional_strict":"0","lenient_values":["1.0","-2"," 3 "],"optional_strict_values":["10"]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                strict: -5,
+                lenient: 5,
+                optional_strict: Some(0),
+                lenient_values: vec![1, -2, 3],
+                optional_strict_values: Some(vec![10]),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_json_strict_invalid() {
+        for strict in ["+5", "05", " 5", "5 ", "-0", "5.0", ""] {
+            let json = format!(
+                r#"{{"strict":"{strict}","lenient":"0","optional_strict":null,"lenient_values":[],"optional_strict_values":null}}"#
+            );
+            let error = serde_json::from_str::<Test>(&json).unwrap_err();
+
+            assert!(
+                error
+                    .to_string()
+                    .contains("expected canonical integer string"),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_json_lenient_invalid() {
+        for lenient in ["++5", "+-5", "1.", "5.1", "-1", "5 0", "+", "."] {
+            let json = format!(
+                r#"{{"strict":"0","lenient":"{lenient}","optional_strict":null,"lenient_values":[],"optional_strict_values":null}}"#
+            );
+
+            assert!(serde_json::from_str::<Test>(&json).is_err());
+        }
+
+        // The array elements are signed, so a `-` after the `+` isn't rejected for the type.
+        for lenient in ["+-5", "-1.", "+5."] {
+            let json = format!(
+                r#"{{"strict":"0","lenient":"0","optional_strict":null,"lenient_values":["{lenient}"],"optional_strict_values":null}}"#
+            );
+
+            assert!(serde_json::from_str::<Test>(&json).is_err(), "{lenient}");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Deserialize an array of integer strings into a collection of integers (and the reverse).
 
+use crate::array::{ElementError, exact_len, preallocation_hint};
 use serde::{
     de::{Deserializer, Visitor},
     ser::Serializer,
@@ -56,7 +57,8 @@ where
 {
     use serde::ser::SerializeSeq;
 
-    let mut seq = serializer.serialize_seq(None)?;
+    let values = values.into_iter();
+    let mut seq = serializer.serialize_seq(exact_len(&values))?;
 
     for value in values {
         seq.serialize_element(&value.to_string())?;
@@ -80,12 +82,16 @@ impl<'de, 'a, A: serde::de::SeqAccess<'de>, E: std::str::FromStr> IntoIterator
     type IntoIter = IntegerStrArraySeqAccessIterator<'de, 'a, A, E>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter { source: self }
+        Self::IntoIter {
+            source: self,
+            index: 0,
+        }
     }
 }
 
 struct IntegerStrArraySeqAccessIterator<'de, 'a, A: serde::de::SeqAccess<'de>, E> {
     source: IntegerStrArraySeqAccessWrapper<'de, 'a, A, E>,
+    index: usize,
 }
 
 impl<'de, A: serde::de::SeqAccess<'de>, E: std::str::FromStr> Iterator
@@ -93,6 +99,10 @@ impl<'de, A: serde::de::SeqAccess<'de>, E: std::str::FromStr> Iterator
 {
     type Item = E;
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        preallocation_hint::<E>(self.source.underlying.size_hint())
+    }
+
     fn next(&mut self) -> Option<Self::Item> {
         if self.source.error.get().is_some() {
             None
@@ -104,15 +114,22 @@ impl<'de, A: serde::de::SeqAccess<'de>, E: std::str::FromStr> Iterator
             {
                 Ok(Some(value)) => {
                     if let Ok(value) = value.parse() {
+                        self.index += 1;
                         Some(value)
                     } else {
+                        let message = <serde::de::value::Error as serde::de::Error>::invalid_value(
+                            serde::de::Unexpected::Str(&value),
+                            &INTEGER_STR_ARRAY_ELEMENT_EXPECTED,
+                        )
+                        .to_string();
+
                         // We've just checked whether the cell is initialized.
                         self.source
                             .error
-                            .set(serde::de::Error::invalid_value(
-                                serde::de::Unexpected::Str(&value),
-                                &INTEGER_STR_ARRAY_ELEMENT_EXPECTED,
-                            ))
+                            .set(serde::de::Error::custom(ElementError {
+                                index: self.index,
+                                message,
+                            }))
                             .unwrap();
                         None
                     }
@@ -120,10 +137,292 @@ impl<'de, A: serde::de::SeqAccess<'de>, E: std::str::FromStr> Iterator
                 Ok(None) => None,
                 Err(error) => {
                     // We've just checked whether the cell is initialized.
-                    self.source.error.set(error).unwrap();
+                    self.source
+                        .error
+                        .set(serde::de::Error::custom(ElementError {
+                            index: self.index,
+                            message: error.to_string(),
+                        }))
+                        .unwrap();
                     None
                 }
             }
         }
     }
 }
+
+/// Strict parsing of elements as in `integer_str::strict`.
+pub mod strict {
+    use crate::{adapter::StrictIntegerStr, array::Array};
+    use serde::de::Deserializer;
+    use std::fmt::Display;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, E: FromStr + Display, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        Array::<StrictIntegerStr>::deserialize(deserializer)
+    }
+}
+
+/// Lenient parsing of elements as in `integer_str::lenient`.
+pub mod lenient {
+    use crate::{adapter::LenientIntegerStr, array::Array};
+    use serde::de::Deserializer;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        Array::<LenientIntegerStr>::deserialize(deserializer)
+    }
+}
+
+/// Arrays of arrays of integer strings (e.g. `Vec<Vec<u64>>`).
+///
+/// Deeper nesting can be expressed with the generic combinator (e.g.
+/// `Array::<Array<Array<adapter::IntegerStr>>>`).
+pub mod nested {
+    use crate::{adapter::IntegerStrWithReason, array::Array};
+    use serde::{de::Deserializer, ser::Serializer};
+    use std::fmt::Display;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub fn deserialize<'de, E: FromStr, R, T: FromIterator<R>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        E::Err: Display,
+        R: IntoIterator<Item = E> + FromIterator<E>,
+    {
+        Array::<Array<IntegerStrWithReason>>::deserialize(deserializer)
+    }
+
+    pub fn serialize<'a, E: Display + 'a, R: IntoIterator<Item = E> + 'a, T: 'a, S: Serializer>(
+        values: &'a T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a R>,
+        for<'b> &'b R: IntoIterator<Item = &'b E>,
+    {
+        Array::<Array<IntegerStrWithReason>>::serialize(values, serializer)
+    }
+}
+
+/// Fixed-size arrays (`[T; N]`), which must have exactly `N` elements.
+pub mod fixed {
+    use crate::{adapter::IntegerStrWithReason, array::FixedArray};
+    use serde::{de::Deserializer, ser::Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub fn deserialize<'de, E: FromStr, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[E; N], D::Error>
+    where
+        E::Err: Display,
+    {
+        FixedArray::<IntegerStrWithReason>::deserialize(deserializer)
+    }
+
+    pub fn serialize<E: Display, const N: usize, S: Serializer>(
+        values: &[E; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        FixedArray::<IntegerStrWithReason>::serialize(values, serializer)
+    }
+}
+
+/// Drop invalid elements instead of failing, as in `tolerant_array::SkipInvalid`.
+pub mod skip_invalid {
+    use crate::{adapter::IntegerStrWithReason, tolerant_array::SkipInvalid};
+    use serde::de::Deserializer;
+    use std::fmt::Display;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        E::Err: Display,
+    {
+        SkipInvalid::<IntegerStrWithReason>::deserialize(deserializer)
+    }
+}
+
+/// Replace invalid elements with the default value, as in `tolerant_array::DefaultInvalid`.
+pub mod default_invalid {
+    use crate::{adapter::IntegerStrWithReason, tolerant_array::DefaultInvalid};
+    use serde::de::Deserializer;
+    use std::fmt::Display;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, E: FromStr + Default, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        E::Err: Display,
+    {
+        DefaultInvalid::<IntegerStrWithReason>::deserialize(deserializer)
+    }
+}
+
+/// Collect the errors for invalid elements alongside the valid elements, as in
+/// `tolerant_array::CollectInvalid`.
+pub mod collect_invalid {
+    use crate::{
+        adapter::IntegerStrWithReason, array::ElementError, tolerant_array::CollectInvalid,
+    };
+    use serde::{de::Deserializer, ser::Serializer};
+    use std::fmt::Display;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(T, Vec<ElementError>), D::Error>
+    where
+        E::Err: Display,
+    {
+        CollectInvalid::<IntegerStrWithReason>::deserialize(deserializer)
+    }
+
+    pub fn serialize<'a, E: Display, T: 'a, S: Serializer>(
+        values: &'a (T, Vec<ElementError>),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = E>,
+    {
+        super::serialize(&values.0, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::ElementError;
+    use serde::de::value::SeqDeserializer;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::integer_str_array::skip_invalid")]
+        skipped: Vec<u8>,
+        #[serde(with = "crate::integer_str_array::default_invalid")]
+        defaulted: Vec<i64>,
+        #[serde(with = "crate::integer_str_array::collect_invalid")]
+        collected: (Vec<u64>, Vec<ElementError>),
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"skipped":["1","256","2"],"defaulted":["x","-3"],"collected":["-1","4"]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                skipped: vec![1, 2],
+                defaulted: vec![0, -3],
+                collected: (
+                    vec![4],
+                    vec![ElementError {
+                        index: 0,
+                        message: "invalid value: string \"-1\", expected integer string (invalid digit found in string)".to_string(),
+                    }],
+                ),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"skipped":["1","2"],"defaulted":["0","-3"],"collected":["4"]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid_element_index() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Values {
+            #[serde(with = "crate::integer_str_array")]
+            #[allow(dead_code)]
+            values: Vec<u64>,
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Values>(r#"{"values":["1","x"]}"#)
+                .unwrap_err()
+                .to_string(),
+            "error at element 1: invalid value: string \"x\", expected integer string at line 1 column 19"
+        );
+        assert_eq!(
+            serde_json::from_str::<Values>(r#"{"values":["1","2",3]}"#)
+                .unwrap_err()
+                .to_string(),
+            "error at element 2: invalid type: integer `3`, expected a string at line 1 column 20"
+        );
+    }
+
+    /// A collection that only records the size hint it was built from.
+    struct SizeHint(usize);
+
+    impl<E> FromIterator<E> for SizeHint {
+        fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+            let iter = iter.into_iter();
+            let hint = iter.size_hint().0;
+
+            iter.for_each(drop);
+            Self(hint)
+        }
+    }
+
+    #[test]
+    fn deserialize_size_hint() {
+        let deserializer =
+            SeqDeserializer::<_, serde::de::value::Error>::new(["1", "2", "3"].into_iter());
+        let SizeHint(hint) =
+            crate::integer_str_array::deserialize::<u64, _, _>(deserializer).unwrap();
+
+        assert_eq!(hint, 3);
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Lengths {
+        #[serde(with = "crate::integer_str_array")]
+        values: Vec<u64>,
+        #[serde(with = "crate::integer_str_array::fixed")]
+        fixed: [i8; 2],
+        #[serde(with = "crate::integer_str_array::nested")]
+        nested: Vec<Vec<u32>>,
+    }
+
+    #[test]
+    fn round_trip_bincode() {
+        let lengths = Lengths {
+            values: vec![u64::MAX, 0],
+            fixed: [-1, 1],
+            nested: vec![vec![1, 2], vec![]],
+        };
+        let bytes = bincode::serialize(&lengths).unwrap();
+
+        assert_eq!(bincode::deserialize::<Lengths>(&bytes).unwrap(), lengths);
+        assert_eq!(
+            serde_json::to_string(&lengths).unwrap(),
+            r#"{"values":["18446744073709551615","0"],"fixed":["-1","1"],"nested":[["1","2"],[]]}"#
+        );
+    }
+}
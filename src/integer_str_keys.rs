@@ -0,0 +1,69 @@
+//! Deserialize a map with integer string keys into a map with integer keys (and the reverse).
+//!
+//! This is equivalent to `map::Map::<adapter::IntegerStr>`, which can be used to apply another
+//! adapter to the values.
+
+use crate::{adapter::IntegerStr, map::Map};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+pub fn deserialize<
+    'de,
+    K: FromStr,
+    V: Deserialize<'de>,
+    T: FromIterator<(K, V)>,
+    D: Deserializer<'de>,
+>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Map::<IntegerStr>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, K: Display + 'a, V: Serialize + 'a, T: 'a, S: Serializer>(
+    values: &'a T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    Map::<IntegerStr>::serialize(values, serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::integer_str_keys")]
+        by_id: HashMap<u64, String>,
+        #[serde(with = "crate::integer_str_keys")]
+        by_offset: BTreeMap<i64, Vec<u8>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"by_id":{"18446744073709551615":"max"},"by_offset":{"-1":[1],"10":[]}}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                by_id: HashMap::from([(u64::MAX, "max".to_string())]),
+                by_offset: BTreeMap::from([(-1, vec![1]), (10, vec![])]),
+            }
+        );
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        assert!(serde_json::from_str::<Test>(r#"{"by_id":{"x":"a"},"by_offset":{}}"#).is_err());
+        assert!(serde_json::from_str::<Test>(r#"{"by_id":{"-1":"a"},"by_offset":{}}"#).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Integers represented as numbers when they can be represented exactly by a JavaScript number,
+//! and as strings otherwise.
+//!
+//! Values between `-MAX_SAFE_INTEGER` and `MAX_SAFE_INTEGER` (2^53 - 1) are serialized as
+//! numbers, and all others as integer strings. Both forms are accepted when deserializing, as in
+//! `integer_or_integer_str`.
+
+use num_traits::PrimInt;
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The value of JavaScript's `Number.MAX_SAFE_INTEGER`.
+pub const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    super::integer_or_integer_str::deserialize(deserializer)
+}
+
+pub fn serialize<T: PrimInt + Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value.to_i64() {
+        Some(value) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) => {
+            serializer.serialize_i64(value)
+        }
+        _ => serializer.serialize_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_SAFE_INTEGER;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::js_safe_integer")]
+        small: u64,
+        #[serde(with = "crate::js_safe_integer")]
+        large: u64,
+        #[serde(with = "crate::optional_js_safe_integer")]
+        optional: Option<i64>,
+        #[serde(with = "crate::js_safe_integer_array")]
+        values: Vec<u128>,
+        #[serde(with = "crate::optional_js_safe_integer_array")]
+        optional_values: Option<Vec<i64>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"small":9007199254740991,"large":"9007199254740992","optional":"-9007199254740992","values":[1,"340282366920938463463374607431768211455"],"optional_values":[-9007199254740991]}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                small: MAX_SAFE_INTEGER as u64,
+                large: MAX_SAFE_INTEGER as u64 + 1,
+                optional: Some(-MAX_SAFE_INTEGER - 1),
+                values: vec![1, u128::MAX],
+                optional_values: Some(vec![-MAX_SAFE_INTEGER]),
+            }
+        );
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_lenient() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"small":"1","large":18446744073709551615,"optional":null,"values":[],"optional_values":null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(test.small, 1);
+        assert_eq!(test.large, u64::MAX);
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"small":1,"large":"18446744073709551615","optional":null,"values":[],"optional_values":null}"#
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Deserialize an array of integers or integer strings into a collection of integers, serializing
+//! each element as in `js_safe_integer`.
+
+use crate::{adapter::JsSafeInteger, array::Array};
+use num_traits::PrimInt;
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Array::<JsSafeInteger>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: PrimInt + Display + 'a, T: 'a, S: Serializer>(
+    values: &'a T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    Array::<JsSafeInteger>::serialize(values, serializer)
+}
@@ -1,25 +1,92 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, rust_2018_idioms)]
 #![allow(clippy::missing_errors_doc)]
 #![forbid(unsafe_code)]
+pub mod adapter;
+pub mod array;
+pub mod basis_points;
+#[cfg(feature = "num-bigint")]
+pub mod bigint_or_bigint_str;
+#[cfg(feature = "num-bigint")]
+pub mod bigint_or_bigint_str_array;
+#[cfg(feature = "num-bigint")]
+pub mod bigint_str;
+#[cfg(feature = "num-bigint")]
+pub mod bigint_str_array;
+pub mod bounded_integer;
+pub mod bounded_integer_str;
+pub mod delimited_str;
+pub mod grouped_integer_str;
+pub mod hex_str;
+pub mod hex_str_array;
 pub mod integer_or_integer_str;
 pub mod integer_str;
 pub mod integer_str_array;
+pub mod integer_str_keys;
+pub mod js_safe_integer;
+pub mod js_safe_integer_array;
+pub mod map;
+pub mod money;
+pub mod optional;
+pub mod optional_basis_points;
+#[cfg(feature = "num-bigint")]
+pub mod optional_bigint_str;
+pub mod optional_delimited_str;
+pub mod optional_hex_str;
+pub mod optional_hex_str_array;
 pub mod optional_integer_str;
 pub mod optional_integer_str_array;
+pub mod optional_js_safe_integer;
+pub mod optional_js_safe_integer_array;
+pub mod optional_percent;
+pub mod optional_percent_str;
+pub mod optional_prefixed_integer_str;
+pub mod optional_prefixed_integer_str_array;
 pub mod optional_range;
+pub mod optional_range_bounds;
+pub mod optional_range_from;
+pub mod optional_range_inclusive;
+pub mod optional_range_inclusive_str;
+pub mod optional_range_object;
+pub mod optional_range_str;
+pub mod optional_range_to;
+pub mod optional_range_to_inclusive;
 pub mod optional_ratio_i64;
+pub mod optional_ratio_str;
 pub mod optional_ratio_u64;
+pub mod optional_represented_as_str;
+pub mod optional_represented_as_str_array;
 pub mod optional_timestamp_millis_str;
 pub mod optional_timestamp_str;
 pub mod optional_usize;
+pub mod percent;
+pub mod percent_str;
+pub mod prefixed_integer_str;
+pub mod prefixed_integer_str_array;
+pub mod radix_str;
 pub mod range;
+pub mod range_bounds;
+pub mod range_from;
+pub mod range_inclusive;
+pub mod range_inclusive_str;
+pub mod range_object;
+pub mod range_set;
+pub mod range_str;
+pub mod range_to;
+pub mod range_to_inclusive;
 pub mod ratio_i64;
+pub mod ratio_or_ratio_str;
+pub mod ratio_str;
 pub mod ratio_u64;
+pub mod represented_as_any_scalar;
 pub mod represented_as_str;
+pub mod represented_as_str_array;
+pub mod sentinel;
 pub mod timestamp_millis_str;
 pub mod timestamp_str;
+pub mod tolerant_array;
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::unreadable_literal)]
 mod tests {
     #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
     struct IntegerStrData {
@@ -406,4 +473,85 @@ mod tests {
 
         assert_eq!(serde_json::json!(value).to_string(), expected);
     }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct OptionalRatioStrData {
+        #[serde(
+            with = "super::optional_ratio_str",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        value: Option<num_rational::Ratio<i64>>,
+    }
+
+    #[test]
+    fn deserialize_some_ratio_str_opt() {
+        let json = r#"{"value":"12.5"}"#;
+        let expected = OptionalRatioStrData {
+            value: Some(num_rational::Ratio::new(25, 2)),
+        };
+
+        assert_eq!(
+            serde_json::from_str::<OptionalRatioStrData>(json).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn deserialize_null_ratio_str_opt() {
+        let json = r#"{"value":null}"#;
+        let expected = OptionalRatioStrData { value: None };
+
+        assert_eq!(
+            serde_json::from_str::<OptionalRatioStrData>(json).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn serialize_some_ratio_str_opt() {
+        let value = OptionalRatioStrData {
+            value: Some(num_rational::Ratio::new(25, 2)),
+        };
+        let expected = r#"{"value":"12.5"}"#;
+
+        assert_eq!(serde_json::json!(value).to_string(), expected);
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct BlankOptData {
+        #[serde(with = "super::optional_integer_str::blank")]
+        id: Option<u64>,
+        #[serde(with = "super::optional_timestamp_str::blank::empty_str")]
+        timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        #[serde(with = "super::optional_range_str::blank")]
+        range: Option<std::ops::Range<u32>>,
+    }
+
+    #[test]
+    fn deserialize_blank_str_opt() {
+        let json = r#"{"id":"","timestamp":" ","range":null}"#;
+        let expected = BlankOptData {
+            id: None,
+            timestamp: None,
+            range: None,
+        };
+
+        assert_eq!(
+            serde_json::from_str::<BlankOptData>(json).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn serialize_blank_str_opt() {
+        let value = BlankOptData {
+            id: None,
+            timestamp: None,
+            range: Some(1..5),
+        };
+        let expected = r#"{"id":null,"range":"1..5","timestamp":""}"#;
+
+        assert_eq!(serde_json::json!(value).to_string(), expected);
+    }
 }
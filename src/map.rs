@@ -0,0 +1,248 @@
+//! Deserialize a map into a collection of key-value pairs, applying separate adapters to the keys
+//! and values (and the reverse).
+//!
+//! This makes it possible to use adapters like `integer_str` or `timestamp_str` for map keys
+//! (which are always strings in JSON) while the values use any other adapter:
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use serde_field_attributes::{adapter, map::Map};
+//! use std::collections::{BTreeMap, HashMap};
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Balances {
+//!     #[serde(with = "Map::<adapter::HexStr, adapter::IntegerStr>")]
+//!     by_account: HashMap<u64, u128>,
+//!     #[serde(with = "Map::<adapter::TimestampStr>")]
+//!     by_time: BTreeMap<DateTime<Utc>, i64>,
+//! }
+//! ```
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserializer, MapAccess, Visitor},
+    ser::{SerializeMap, Serializer},
+};
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+
+const EXPECTED: &str = "map";
+
+pub struct Map<KA, VA = Plain> {
+    _key_adapter: PhantomData<KA>,
+    _value_adapter: PhantomData<VA>,
+}
+
+impl<KA, VA> Map<KA, VA> {
+    pub fn deserialize<'de, K, V, T: FromIterator<(K, V)>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        KA: DeserializeAdapter<'de, K>,
+        VA: DeserializeAdapter<'de, V>,
+    {
+        struct MapVisitor<KA, VA, K, V, T> {
+            _key_adapter: PhantomData<KA>,
+            _value_adapter: PhantomData<VA>,
+            _key: PhantomData<K>,
+            _value: PhantomData<V>,
+            _target: PhantomData<T>,
+        }
+
+        impl<
+            'de,
+            KA: DeserializeAdapter<'de, K>,
+            VA: DeserializeAdapter<'de, V>,
+            K,
+            V,
+            T: FromIterator<(K, V)>,
+        > Visitor<'de> for MapVisitor<KA, VA, K, V, T>
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str(EXPECTED)
+            }
+
+            fn visit_map<M: MapAccess<'de>>(self, map: M) -> Result<Self::Value, M::Error> {
+                let mut error = OnceCell::new();
+
+                let result = MapAccessIterator::<'de, '_, M, KA, VA, K, V> {
+                    underlying: map,
+                    error: &mut error,
+                    _key_adapter: PhantomData,
+                    _value_adapter: PhantomData,
+                    _key: PhantomData,
+                    _value: PhantomData,
+                }
+                .collect();
+
+                error.take().map_or_else(|| Ok(result), |error| Err(error))
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor::<KA, VA, K, V, T> {
+            _key_adapter: PhantomData,
+            _value_adapter: PhantomData,
+            _key: PhantomData,
+            _value: PhantomData,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<'a, K: 'a, V: 'a, T: 'a, S: Serializer>(
+        values: &'a T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = (&'a K, &'a V)>,
+        KA: SerializeAdapter<K>,
+        VA: SerializeAdapter<V>,
+    {
+        let values = values.into_iter();
+        let mut map = serializer.serialize_map(crate::array::exact_len(&values))?;
+
+        for (key, value) in values {
+            map.serialize_entry(
+                &Serializable::<KA, K>::new(key),
+                &Serializable::<VA, V>::new(value),
+            )?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de, KA, VA, K, V, T> DeserializeAdapter<'de, T> for Map<KA, VA>
+where
+    KA: DeserializeAdapter<'de, K>,
+    VA: DeserializeAdapter<'de, V>,
+    T: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<KA, VA, K, V, T> SerializeAdapter<T> for Map<KA, VA>
+where
+    KA: SerializeAdapter<K>,
+    VA: SerializeAdapter<V>,
+    T: IntoIterator<Item = (K, V)>,
+    for<'a> &'a T: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+struct MapAccessIterator<'de, 'a, M: MapAccess<'de>, KA, VA, K, V> {
+    underlying: M,
+    error: &'a mut OnceCell<M::Error>,
+    _key_adapter: PhantomData<KA>,
+    _value_adapter: PhantomData<VA>,
+    _key: PhantomData<K>,
+    _value: PhantomData<V>,
+}
+
+impl<'de, M: MapAccess<'de>, KA: DeserializeAdapter<'de, K>, VA: DeserializeAdapter<'de, V>, K, V>
+    Iterator for MapAccessIterator<'de, '_, M, KA, VA, K, V>
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.get().is_some() {
+            None
+        } else {
+            match self
+                .underlying
+                .next_entry::<Deserializable<KA, K>, Deserializable<VA, V>>()
+            {
+                Ok(Some((key, value))) => Some((key.value, value.value)),
+                Ok(None) => None,
+                Err(error) => {
+                    // We've just checked whether the cell is initialized.
+                    self.error.set(error).unwrap();
+                    None
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Map;
+    use crate::adapter::{HexStr, IntegerStr, Plain, TimestampStr};
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "Map::<HexStr, IntegerStr>")]
+        balances: BTreeMap<u64, u128>,
+        #[serde(with = "Map::<TimestampStr>")]
+        events: BTreeMap<DateTime<Utc>, String>,
+        #[serde(with = "Map::<Plain, Map<IntegerStr>>")]
+        nested: HashMap<String, BTreeMap<i32, bool>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"balances":{"0xff":"1","0x1":"2"},"events":{"1609459200":"start"},"nested":{"a":{"-1":true}}}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                balances: BTreeMap::from([(1, 2), (255, 1)]),
+                events: BTreeMap::from([(
+                    Utc.timestamp_opt(1_609_459_200, 0).unwrap(),
+                    "start".to_string(),
+                )]),
+                nested: HashMap::from([("a".to_string(), BTreeMap::from([(-1, true)]))]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"balances":{"0x1":"2","0xff":"1"},"events":{"1609459200":"start"},"nested":{"a":{"-1":true}}}"#
+        );
+    }
+
+    #[test]
+    fn round_trip_bincode() {
+        let test = Test {
+            balances: BTreeMap::from([(1, u128::MAX)]),
+            events: BTreeMap::new(),
+            nested: HashMap::from([("a".to_string(), BTreeMap::from([(-1, true)]))]),
+        };
+        let bytes = bincode::serialize(&test).unwrap();
+
+        assert_eq!(bincode::deserialize::<Test>(&bytes).unwrap(), test);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"balances":{"0xfg":"1"},"events":{},"nested":{}}"#,
+            r#"{"balances":{"0x1":1},"events":{},"nested":{}}"#,
+            r#"{"balances":{},"events":{"x":"start"},"nested":{}}"#,
+            r#"{"balances":{},"events":{},"nested":{"a":{"1.5":true}}}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn deserialize_pairs() {
+        #[derive(serde::Deserialize)]
+        struct Pairs {
+            #[serde(deserialize_with = "Map::<IntegerStr>::deserialize")]
+            pairs: Vec<(u64, u64)>,
+        }
+
+        let pairs = serde_json::from_str::<Pairs>(r#"{"pairs":{"2":1,"1":2}}"#).unwrap();
+
+        assert_eq!(pairs.pairs, vec![(2, 1), (1, 2)]);
+    }
+}
@@ -0,0 +1,399 @@
+//! Deserialize a monetary amount with an ISO 4217 currency code (and the reverse).
+//!
+//! The representation is an object with `amount` and `currency` fields. Integer amounts are
+//! interpreted as minor units (so `{"amount": 1999, "currency": "USD"}` is 19.99 USD), while
+//! decimal string amounts are interpreted as major units (`{"amount": "19.99", "currency": "USD"}`).
+//! Decimal amounts with more decimal places than the currency allows are rejected. Integer amounts
+//! are handled as in `integer_or_integer_str`, but strings are always decimal major units here.
+//!
+//! Serialization always uses decimal strings with exactly as many decimal places as the currency
+//! specifies. The [`minor_units`] module provides a variant that only uses minor units (including
+//! for integer strings).
+
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+use serde::{
+    de::{Deserialize, Deserializer, IntoDeserializer, Unexpected, Visitor},
+    ser::{Serialize, Serializer},
+};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Currencies whose minor unit exponent is not two (as of the 2024 ISO 4217 list, excluding codes
+/// with no minor unit, such as precious metals).
+const EXPONENTS: [(&str, u32); 26] = [
+    ("BHD", 3),
+    ("BIF", 0),
+    ("CLF", 4),
+    ("CLP", 0),
+    ("DJF", 0),
+    ("GNF", 0),
+    ("IQD", 3),
+    ("ISK", 0),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KMF", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("LYD", 3),
+    ("OMR", 3),
+    ("PYG", 0),
+    ("RWF", 0),
+    ("TND", 3),
+    ("UGX", 0),
+    ("UYI", 0),
+    ("UYW", 4),
+    ("VND", 0),
+    ("VUV", 0),
+    ("XAF", 0),
+    ("XOF", 0),
+    ("XPF", 0),
+];
+
+const DEFAULT_EXPONENT: u32 = 2;
+
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    #[error("invalid currency code: {0:?}")]
+    InvalidCurrencyCode(String),
+    #[error("too many decimal places for {currency} (at most {exponent})")]
+    TooManyDecimalPlaces { currency: Currency, exponent: u32 },
+    #[error("amount out of range for {0}")]
+    OutOfRange(Currency),
+}
+
+/// An ISO 4217 currency code.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Currency {
+    code: [u8; 3],
+}
+
+impl Currency {
+    pub fn new(code: &str) -> Result<Self, Error> {
+        code.as_bytes()
+            .try_into()
+            .ok()
+            .filter(|code: &[u8; 3]| code.iter().all(u8::is_ascii_uppercase))
+            .map(|code| Self { code })
+            .ok_or_else(|| Error::InvalidCurrencyCode(code.to_string()))
+    }
+
+    #[must_use]
+    pub fn code(&self) -> &str {
+        // The code is always three ASCII uppercase letters, so this never falls back.
+        std::str::from_utf8(&self.code).unwrap_or_default()
+    }
+
+    /// The number of decimal places used by the currency's minor unit.
+    #[must_use]
+    pub fn exponent(&self) -> u32 {
+        EXPONENTS
+            .iter()
+            .find(|(code, _)| *code == self.code())
+            .map_or(DEFAULT_EXPONENT, |(_, exponent)| *exponent)
+    }
+
+    fn minor_units_per_major_unit(self) -> Result<i64, Error> {
+        10i64
+            .checked_pow(self.exponent())
+            .ok_or(Error::OutOfRange(self))
+    }
+}
+
+impl FromStr for Currency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// An amount of money, stored as an integer number of minor units.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Money {
+    pub minor_units: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    #[must_use]
+    pub const fn new(minor_units: i64, currency: Currency) -> Self {
+        Self {
+            minor_units,
+            currency,
+        }
+    }
+
+    /// Create a value from an amount in major units, failing if the amount has too many decimal
+    /// places for the currency.
+    pub fn from_amount(amount: &Ratio<i64>, currency: Currency) -> Result<Self, Error> {
+        let minor_units = amount
+            .checked_mul(&Ratio::from_integer(currency.minor_units_per_major_unit()?))
+            .ok_or(Error::OutOfRange(currency))?;
+
+        if minor_units.is_integer() {
+            Ok(Self::new(minor_units.to_integer(), currency))
+        } else {
+            Err(Error::TooManyDecimalPlaces {
+                currency,
+                exponent: currency.exponent(),
+            })
+        }
+    }
+
+    /// The amount in major units.
+    pub fn amount(&self) -> Result<Ratio<i64>, Error> {
+        Ok(Ratio::new(
+            self.minor_units,
+            self.currency.minor_units_per_major_unit()?,
+        ))
+    }
+
+    fn format_amount(&self) -> String {
+        let places = self.currency.exponent() as usize;
+        let digits = format!(
+            "{:0>width$}",
+            self.minor_units.unsigned_abs(),
+            width = places + 1
+        );
+        let (integer, fraction) = digits.split_at(digits.len() - places);
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+
+        if places == 0 {
+            format!("{sign}{integer}")
+        } else {
+            format!("{sign}{integer}.{fraction}")
+        }
+    }
+}
+
+enum Amount {
+    MinorUnits(i64),
+    Decimal(Ratio<i64>),
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const EXPECTED: &str = "integer minor units or decimal string";
+
+        struct AmountVisitor;
+
+        impl Visitor<'_> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str(EXPECTED)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                minor_units(v)
+            }
+
+            fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+                minor_units(v)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                minor_units(v)
+            }
+
+            fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+                minor_units(v)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                super::ratio_str::parse_decimal(v)
+                    .map(Amount::Decimal)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &EXPECTED))
+            }
+        }
+
+        /// Parse integer minor units in the same way as the `minor_units` module.
+        fn minor_units<'de, E: serde::de::Error, V: IntoDeserializer<'de, E>>(
+            v: V,
+        ) -> Result<Amount, E> {
+            super::integer_or_integer_str::deserialize(v.into_deserializer())
+                .map(Amount::MinorUnits)
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct MoneyRepr {
+    amount: Amount,
+    #[serde(with = "super::represented_as_str")]
+    currency: Currency,
+}
+
+#[derive(serde::Serialize)]
+struct MoneyReprRef<'a, A> {
+    amount: A,
+    #[serde(with = "super::represented_as_str")]
+    currency: &'a Currency,
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+    let MoneyRepr { amount, currency } = MoneyRepr::deserialize(deserializer)?;
+
+    match amount {
+        Amount::MinorUnits(minor_units) => Ok(Money::new(minor_units, currency)),
+        Amount::Decimal(amount) => {
+            Money::from_amount(&amount, currency).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+pub fn serialize<S: Serializer>(value: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+    MoneyReprRef {
+        amount: value.format_amount(),
+        currency: &value.currency,
+    }
+    .serialize(serializer)
+}
+
+/// Amounts are always given in minor units, as either integers or integer strings.
+pub mod minor_units {
+    use super::{Currency, Money, MoneyReprRef};
+    use serde::{
+        de::{Deserialize, Deserializer},
+        ser::{Serialize, Serializer},
+    };
+
+    #[derive(serde::Deserialize)]
+    struct MinorUnitsRepr {
+        #[serde(with = "crate::integer_or_integer_str")]
+        amount: i64,
+        #[serde(with = "crate::represented_as_str")]
+        currency: Currency,
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        let MinorUnitsRepr { amount, currency } = MinorUnitsRepr::deserialize(deserializer)?;
+
+        Ok(Money::new(amount, currency))
+    }
+
+    pub fn serialize<S: Serializer>(value: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyReprRef {
+            amount: value.minor_units,
+            currency: &value.currency,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Currency, Money};
+    use num_rational::Ratio;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "super")]
+        price: Money,
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct MinorUnitsTest {
+        #[serde(with = "super::minor_units")]
+        price: Money,
+    }
+
+    fn usd() -> Currency {
+        Currency::new("USD").unwrap()
+    }
+
+    #[test]
+    fn currency_exponent() {
+        assert_eq!(usd().exponent(), 2);
+        assert_eq!(Currency::new("JPY").unwrap().exponent(), 0);
+        assert_eq!(Currency::new("KWD").unwrap().exponent(), 3);
+        assert_eq!(Currency::new("XOF").unwrap().exponent(), 0);
+        assert_eq!(Currency::new("XPF").unwrap().exponent(), 0);
+        assert!(Currency::new("usd").is_err());
+        assert!(Currency::new("USDT").is_err());
+    }
+
+    #[test]
+    fn parse_json() {
+        let from_minor_units =
+            serde_json::from_str::<Test>(r#"{"price":{"amount":1999,"currency":"USD"}}"#).unwrap();
+        let from_decimal =
+            serde_json::from_str::<Test>(r#"{"price":{"currency":"USD","amount":"19.99"}}"#)
+                .unwrap();
+
+        assert_eq!(from_minor_units.price, Money::new(1999, usd()));
+        assert_eq!(from_decimal, from_minor_units);
+        assert_eq!(from_decimal.price.amount(), Ok(Ratio::new(1999, 100)));
+    }
+
+    #[test]
+    fn parse_json_too_many_decimal_places() {
+        assert!(
+            serde_json::from_str::<Test>(r#"{"price":{"amount":"19.999","currency":"USD"}}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Test>(r#"{"price":{"amount":"19.5","currency":"JPY"}}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Test>(r#"{"price":{"amount":"100.50","currency":"XOF"}}"#)
+                .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Test>(r#"{"price":{"amount":"19.500","currency":"KWD"}}"#)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn parse_json_minor_units_out_of_range() {
+        let json = r#"{"price":{"amount":9223372036854775808,"currency":"USD"}}"#;
+        let error = serde_json::from_str::<Test>(json).unwrap_err();
+        let minor_units_error = serde_json::from_str::<MinorUnitsTest>(json).unwrap_err();
+
+        assert_eq!(error.to_string(), minor_units_error.to_string());
+    }
+
+    #[test]
+    fn round_trip_json() {
+        for (value, expected) in [
+            (
+                Money::new(-5, usd()),
+                r#"{"price":{"amount":"-0.05","currency":"USD"}}"#,
+            ),
+            (
+                Money::new(1990, Currency::new("JPY").unwrap()),
+                r#"{"price":{"amount":"1990","currency":"JPY"}}"#,
+            ),
+        ] {
+            let value = Test { price: value };
+            let serialized = serde_json::json!(value).to_string();
+            let deserialized = serde_json::from_str::<Test>(&serialized).unwrap();
+
+            assert_eq!(serialized, expected);
+            assert_eq!(deserialized, value);
+        }
+    }
+
+    #[test]
+    fn round_trip_json_minor_units() {
+        let value = serde_json::from_str::<MinorUnitsTest>(
+            r#"{"price":{"amount":"1999","currency":"USD"}}"#,
+        )
+        .unwrap();
+        let serialized = serde_json::json!(value).to_string();
+
+        assert_eq!(value.price, Money::new(1999, usd()));
+        assert_eq!(serialized, r#"{"price":{"amount":1999,"currency":"USD"}}"#);
+    }
+}
@@ -0,0 +1,46 @@
+//! Optional values whose present values use an element adapter (e.g.
+//! `Optional::<adapter::HexStr>`), with `null` indicating absence.
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::Serializer,
+};
+use std::marker::PhantomData;
+
+pub struct Optional<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> Optional<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let value: Option<Deserializable<A, T>> = Deserialize::deserialize(deserializer)?;
+
+        Ok(value.map(|value| value.value))
+    }
+
+    pub fn serialize<T, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => serializer.serialize_some(&Serializable::<A, T>::new(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, Option<T>> for Optional<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<T>, T> SerializeAdapter<Option<T>> for Optional<A> {
+    fn serialize<S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
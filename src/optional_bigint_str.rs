@@ -0,0 +1,17 @@
+use crate::{adapter::BigIntStr, bigint_str::BigInteger, optional::Optional};
+use serde::{de::Deserializer, ser::Serializer};
+
+pub fn deserialize<'de, T: BigInteger, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    Optional::<BigIntStr>::deserialize(deserializer)
+}
+
+pub fn serialize<T: BigInteger, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Optional::<BigIntStr>::serialize(value, serializer)
+}
+
+crate::sentinel::blank_modules!(crate::adapter::BigIntStr);
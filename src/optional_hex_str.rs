@@ -0,0 +1,18 @@
+use crate::{adapter::HexStr, optional::Optional};
+use num_traits::{Num, PrimInt};
+use serde::{de::Deserializer, ser::Serializer};
+
+pub fn deserialize<'de, T: Num, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    Optional::<HexStr>::deserialize(deserializer)
+}
+
+pub fn serialize<T: PrimInt, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Optional::<HexStr>::serialize(value, serializer)
+}
+
+crate::sentinel::blank_modules!(crate::adapter::HexStr);
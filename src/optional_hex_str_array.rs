@@ -0,0 +1,20 @@
+use crate::{adapter::HexStr, array::OptionalArray};
+use num_traits::{Num, PrimInt};
+use serde::{de::Deserializer, ser::Serializer};
+use std::iter::FromIterator;
+
+pub fn deserialize<'de, E: Num, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    OptionalArray::<HexStr>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: PrimInt + 'a, T: 'a, S: Serializer>(
+    values: &'a Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    OptionalArray::<HexStr>::serialize(values, serializer)
+}
@@ -47,3 +47,36 @@ pub fn serialize<T: std::fmt::Display, S: Serializer>(
         None => serializer.serialize_none(),
     }
 }
+
+crate::sentinel::blank_modules!(crate::adapter::IntegerStr);
+
+/// Strict parsing as in `integer_str::strict`.
+pub mod strict {
+    use crate::{adapter::StrictIntegerStr, optional::Optional};
+    use serde::de::Deserializer;
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: FromStr + Display, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Optional::<StrictIntegerStr>::deserialize(deserializer)
+    }
+}
+
+/// Lenient parsing as in `integer_str::lenient`.
+pub mod lenient {
+    use crate::{adapter::LenientIntegerStr, optional::Optional};
+    use serde::de::Deserializer;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Optional::<LenientIntegerStr>::deserialize(deserializer)
+    }
+}
@@ -53,3 +53,36 @@ where
         None => serializer.serialize_none(),
     }
 }
+
+/// Strict parsing of elements as in `integer_str::strict`.
+pub mod strict {
+    use crate::{adapter::StrictIntegerStr, array::OptionalArray};
+    use serde::de::Deserializer;
+    use std::fmt::Display;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, E: FromStr + Display, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        OptionalArray::<StrictIntegerStr>::deserialize(deserializer)
+    }
+}
+
+/// Lenient parsing of elements as in `integer_str::lenient`.
+pub mod lenient {
+    use crate::{adapter::LenientIntegerStr, array::OptionalArray};
+    use serde::de::Deserializer;
+    use std::iter::FromIterator;
+    use std::str::FromStr;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        OptionalArray::<LenientIntegerStr>::deserialize(deserializer)
+    }
+}
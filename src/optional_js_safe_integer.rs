@@ -0,0 +1,18 @@
+use crate::{adapter::JsSafeInteger, optional::Optional};
+use num_traits::PrimInt;
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    Optional::<JsSafeInteger>::deserialize(deserializer)
+}
+
+pub fn serialize<T: PrimInt + Display, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Optional::<JsSafeInteger>::serialize(value, serializer)
+}
@@ -0,0 +1,22 @@
+use crate::{adapter::JsSafeInteger, array::OptionalArray};
+use num_traits::PrimInt;
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    OptionalArray::<JsSafeInteger>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: PrimInt + Display + 'a, T: 'a, S: Serializer>(
+    values: &'a Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    OptionalArray::<JsSafeInteger>::serialize(values, serializer)
+}
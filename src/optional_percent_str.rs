@@ -0,0 +1,46 @@
+use num_rational::Ratio;
+use serde::{
+    de::{Deserializer, Visitor},
+    ser::Serializer,
+};
+
+const EXPECTED: &str = "optional percentage string";
+
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Ratio<i64>>, D::Error> {
+    struct PercentStrOptVisitor;
+
+    impl<'de> Visitor<'de> for PercentStrOptVisitor {
+        type Value = Option<Ratio<i64>>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            super::percent_str::deserialize(deserializer).map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(PercentStrOptVisitor)
+}
+
+pub fn serialize<S: Serializer>(
+    value: &Option<Ratio<i64>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => super::percent_str::serialize(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+crate::sentinel::blank_modules!(crate::adapter::PercentStr);
@@ -1,28 +1,99 @@
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, SerializeTuple, Serializer},
 };
+use std::marker::PhantomData;
 use std::ops::Range;
 
 pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<Option<Range<T>>, D::Error> {
-    let range: Option<(T, T)> = Deserialize::deserialize(deserializer)?;
-
-    Ok(range.map(|(start, end)| start..end))
+    With::<Plain>::deserialize(deserializer)
 }
 
 pub fn serialize<T: Serialize, S: Serializer>(
     value: &Option<Range<T>>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
-    match value {
-        Some(value) => {
-            let mut tuple = serializer.serialize_tuple(2)?;
-            tuple.serialize_element(&value.start)?;
-            tuple.serialize_element(&value.end)?;
-            tuple.end()
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// An optional range whose endpoints are deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Range<T>>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let range: Option<(Deserializable<A, T>, Deserializable<A, T>)> =
+            Deserialize::deserialize(deserializer)?;
+
+        Ok(range.map(|(start, end)| start.value..end.value))
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &Option<Range<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&Serializable::<A, T>::new(&value.start))?;
+                tuple.serialize_element(&Serializable::<A, T>::new(&value.end))?;
+                tuple.end()
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Reject ranges whose start is greater than their end.
+pub mod checked {
+    use serde::de::{Deserialize, Deserializer};
+    use std::fmt::Display;
+    use std::ops::Range;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: Deserialize<'de> + PartialOrd + Display, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Range<T>>, D::Error> {
+        let range = super::deserialize(deserializer)?;
+
+        if let Some(range) = &range {
+            crate::range::validate(range, true).map_err(serde::de::Error::custom)?;
+        }
+
+        Ok(range)
+    }
+}
+
+/// Reject ranges whose start is greater than or equal to their end.
+pub mod non_empty {
+    use serde::de::{Deserialize, Deserializer};
+    use std::fmt::Display;
+    use std::ops::Range;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: Deserialize<'de> + PartialOrd + Display, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Range<T>>, D::Error> {
+        let range = super::deserialize(deserializer)?;
+
+        if let Some(range) = &range {
+            crate::range::validate(range, false).map_err(serde::de::Error::custom)?;
         }
-        None => serializer.serialize_none(),
+
+        Ok(range)
     }
 }
@@ -0,0 +1,70 @@
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+type Bounds<T> = (Bound<T>, Bound<T>);
+type Endpoints<A, T> = (
+    Option<Bound<Deserializable<A, T>>>,
+    Option<Bound<Deserializable<A, T>>>,
+);
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Bounds<T>>, D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &Option<Bounds<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// An optional pair of bounds whose values are deserialized and serialized with the given
+/// adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Bounds<T>>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let range: Option<Endpoints<A, T>> = Deserialize::deserialize(deserializer)?;
+
+        Ok(range.map(|(start, end)| {
+            (
+                super::range_bounds::unbounded_if_none(start),
+                super::range_bounds::unbounded_if_none(end),
+            )
+        }))
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &Option<Bounds<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple
+                    .serialize_element(&super::range_bounds::none_if_unbounded::<A, T>(&value.0))?;
+                tuple
+                    .serialize_element(&super::range_bounds::none_if_unbounded::<A, T>(&value.1))?;
+                tuple.end()
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+}
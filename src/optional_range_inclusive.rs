@@ -0,0 +1,57 @@
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RangeInclusive<T>>, D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &Option<RangeInclusive<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// An optional range whose endpoints are deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RangeInclusive<T>>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let range: Option<(Deserializable<A, T>, Deserializable<A, T>)> =
+            Deserialize::deserialize(deserializer)?;
+
+        Ok(range.map(|(start, end)| start.value..=end.value))
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &Option<RangeInclusive<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&Serializable::<A, T>::new(value.start()))?;
+                tuple.serialize_element(&Serializable::<A, T>::new(value.end()))?;
+                tuple.end()
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+}
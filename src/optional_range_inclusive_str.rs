@@ -0,0 +1,89 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+const EXPECTED: &str = "optional inclusive range string";
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RangeInclusive<T>>, D::Error> {
+    struct RangeInclusiveStrOptVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: FromStr> Visitor<'de> for RangeInclusiveStrOptVisitor<T> {
+        type Value = Option<RangeInclusive<T>>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            super::range_inclusive_str::deserialize(deserializer).map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(RangeInclusiveStrOptVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: Display, S: Serializer>(
+    value: &Option<RangeInclusive<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => super::range_inclusive_str::serialize(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub mod dash {
+    use serde::ser::Serializer;
+    use std::fmt::Display;
+    use std::ops::RangeInclusive;
+
+    pub use super::deserialize;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<RangeInclusive<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => crate::range_inclusive_str::dash::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+pub mod interval {
+    use serde::ser::Serializer;
+    use std::fmt::Display;
+    use std::ops::RangeInclusive;
+
+    pub use super::deserialize;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<RangeInclusive<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => crate::range_inclusive_str::interval::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+crate::sentinel::blank_modules!(crate::adapter::RangeInclusiveStr);
@@ -0,0 +1,136 @@
+use super::range_object::{FieldNames, StartEnd};
+use crate::adapter::{DeserializeAdapter, Plain, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer, Visitor},
+    ser::{Serialize, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+
+const EXPECTED: &str = "optional range object";
+
+pub struct OptionalRangeObject<N = StartEnd, A = Plain> {
+    _names: PhantomData<N>,
+    _adapter: PhantomData<A>,
+}
+
+impl<N: FieldNames, A> OptionalRangeObject<N, A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Range<T>>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let range = deserialize_endpoints::<N, A, T, D>(deserializer)?;
+
+        Ok(range.map(|(start, end)| start..end))
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &Option<Range<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => super::range_object::serialize_endpoints::<N, A, T, S>(
+                &value.start,
+                &value.end,
+                serializer,
+            ),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+pub struct OptionalRangeInclusiveObject<N = StartEnd, A = Plain> {
+    _names: PhantomData<N>,
+    _adapter: PhantomData<A>,
+}
+
+impl<N: FieldNames, A> OptionalRangeInclusiveObject<N, A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RangeInclusive<T>>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let range = deserialize_endpoints::<N, A, T, D>(deserializer)?;
+
+        Ok(range.map(|(start, end)| start..=end))
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &Option<RangeInclusive<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => super::range_object::serialize_endpoints::<N, A, T, S>(
+                value.start(),
+                value.end(),
+                serializer,
+            ),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Range<T>>, D::Error> {
+    OptionalRangeObject::<StartEnd>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &Option<Range<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    OptionalRangeObject::<StartEnd>::serialize(value, serializer)
+}
+
+fn deserialize_endpoints<
+    'de,
+    N: FieldNames,
+    A: DeserializeAdapter<'de, T>,
+    T,
+    D: Deserializer<'de>,
+>(
+    deserializer: D,
+) -> Result<Option<(T, T)>, D::Error> {
+    struct RangeObjectOptVisitor<N, A, T> {
+        _names: PhantomData<N>,
+        _adapter: PhantomData<A>,
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, N: FieldNames, A: DeserializeAdapter<'de, T>, T> Visitor<'de>
+        for RangeObjectOptVisitor<N, A, T>
+    {
+        type Value = Option<(T, T)>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            super::range_object::deserialize_endpoints::<N, A, T, D>(deserializer).map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(RangeObjectOptVisitor::<N, A, T> {
+        _names: PhantomData,
+        _adapter: PhantomData,
+        _target: PhantomData,
+    })
+}
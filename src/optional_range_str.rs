@@ -0,0 +1,71 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::str::FromStr;
+
+const EXPECTED: &str = "optional half-open range string";
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Range<T>>, D::Error> {
+    struct RangeStrOptVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: FromStr> Visitor<'de> for RangeStrOptVisitor<T> {
+        type Value = Option<Range<T>>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            super::range_str::deserialize(deserializer).map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(RangeStrOptVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: Display, S: Serializer>(
+    value: &Option<Range<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => super::range_str::serialize(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub mod interval {
+    use serde::ser::Serializer;
+    use std::fmt::Display;
+    use std::ops::Range;
+
+    pub use super::deserialize;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Option<Range<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => crate::range_str::interval::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+crate::sentinel::blank_modules!(crate::adapter::RangeStr);
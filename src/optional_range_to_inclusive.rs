@@ -0,0 +1,70 @@
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer, IgnoredAny, Unexpected},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::RangeToInclusive;
+
+const EXPECTED: &str = "optional range with null start";
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<RangeToInclusive<T>>, D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &Option<RangeToInclusive<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// An optional range whose end is deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<RangeToInclusive<T>>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let range: Option<(Option<IgnoredAny>, Deserializable<A, T>)> =
+            Deserialize::deserialize(deserializer)?;
+
+        range
+            .map(|(start, end)| {
+                if start.is_some() {
+                    Err(serde::de::Error::invalid_value(
+                        Unexpected::Other("bounded start"),
+                        &EXPECTED,
+                    ))
+                } else {
+                    Ok(..=end.value)
+                }
+            })
+            .transpose()
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &Option<RangeToInclusive<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&None::<()>)?;
+                tuple.serialize_element(&Serializable::<A, T>::new(&value.end))?;
+                tuple.end()
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+use serde::{
+    de::{Deserializer, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+const EXPECTED: &str = "optional decimal string";
+
+pub fn deserialize<'de, T: Clone + Integer + FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Ratio<T>>, D::Error> {
+    struct RatioStrOptVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: Clone + Integer + FromStr> Visitor<'de> for RatioStrOptVisitor<T> {
+        type Value = Option<Ratio<T>>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            super::ratio_str::deserialize(deserializer).map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(RatioStrOptVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: Clone + Integer + CheckedMul + Display, S: Serializer>(
+    value: &Option<Ratio<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => super::ratio_str::serialize(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+crate::sentinel::blank_modules!(crate::adapter::RatioStr);
@@ -0,0 +1,22 @@
+use crate::{adapter::RepresentedAsStr, optional::Optional};
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error>
+where
+    T::Err: Display,
+{
+    Optional::<RepresentedAsStr>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Display, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Optional::<RepresentedAsStr>::serialize(value, serializer)
+}
+
+crate::sentinel::blank_modules!(crate::adapter::RepresentedAsStr);
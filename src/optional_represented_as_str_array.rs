@@ -0,0 +1,24 @@
+use crate::{adapter::RepresentedAsStr, array::OptionalArray};
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error>
+where
+    E::Err: Display,
+{
+    OptionalArray::<RepresentedAsStr>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: Display + 'a, T: 'a, S: Serializer>(
+    values: &'a Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    OptionalArray::<RepresentedAsStr>::serialize(values, serializer)
+}
@@ -42,3 +42,5 @@ pub fn serialize<S: Serializer>(
         None => serializer.serialize_none(),
     }
 }
+
+crate::sentinel::blank_modules!(crate::adapter::TimestampStr);
@@ -1,42 +1,195 @@
-//! An optional unsigned integer representation where `-1` indicates absence.
+//! An optional integer representation where `-1` indicates absence.
+//!
+//! Despite the name, any primitive integer type is supported, and integer strings (e.g. `"42"` or
+//! `"-1"`) are also accepted. Since `-1` is reserved, serializing `Some(-1)` for a signed type is an
+//! error. See the `sentinel` module for other sentinel values.
 use serde::{
     de::{Deserializer, Unexpected, Visitor},
-    ser::Serializer,
+    ser::{Serialize, Serializer},
 };
+use std::marker::PhantomData;
 
-const EXPECTED: &str = "optional unsigned integer";
+const EXPECTED: &str = "optional integer";
 
-pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<usize>, D::Error> {
-    struct UsizeOptVisitor;
+pub fn deserialize<'de, T: TryFrom<i128> + TryFrom<u128>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    struct IntegerOptVisitor<T> {
+        _target: PhantomData<T>,
+    }
 
-    impl Visitor<'_> for UsizeOptVisitor {
-        type Value = Option<usize>;
+    impl<T: TryFrom<i128> + TryFrom<u128>> Visitor<'_> for IntegerOptVisitor<T> {
+        type Value = Option<T>;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             formatter.write_str(EXPECTED)
         }
 
+        fn visit_u8<E: serde::de::Error>(self, v: u8) -> Result<Self::Value, E> {
+            self.visit_u64(v.into())
+        }
+
+        fn visit_u16<E: serde::de::Error>(self, v: u16) -> Result<Self::Value, E> {
+            self.visit_u64(v.into())
+        }
+
+        fn visit_u32<E: serde::de::Error>(self, v: u32) -> Result<Self::Value, E> {
+            self.visit_u64(v.into())
+        }
+
         fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
-            v.try_into()
+            T::try_from(u128::from(v))
                 .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &EXPECTED))
                 .map(Some)
         }
 
+        fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            T::try_from(v)
+                .map_err(|_| E::invalid_value(Unexpected::Other(&v.to_string()), &EXPECTED))
+                .map(Some)
+        }
+
+        fn visit_i8<E: serde::de::Error>(self, v: i8) -> Result<Self::Value, E> {
+            self.visit_i64(v.into())
+        }
+
+        fn visit_i16<E: serde::de::Error>(self, v: i16) -> Result<Self::Value, E> {
+            self.visit_i64(v.into())
+        }
+
+        fn visit_i32<E: serde::de::Error>(self, v: i32) -> Result<Self::Value, E> {
+            self.visit_i64(v.into())
+        }
+
         fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
             if v == -1 {
                 Ok(None)
             } else {
-                Err(E::invalid_value(Unexpected::Signed(v), &EXPECTED))
+                T::try_from(i128::from(v))
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(v), &EXPECTED))
+                    .map(Some)
+            }
+        }
+
+        fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            if v == -1 {
+                Ok(None)
+            } else {
+                T::try_from(v)
+                    .map_err(|_| E::invalid_value(Unexpected::Other(&v.to_string()), &EXPECTED))
+                    .map(Some)
+            }
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let invalid = || E::invalid_value(Unexpected::Str(v), &EXPECTED);
+
+            // Integer parsing accepts a `+` sign, which we don't.
+            if v.starts_with('+') {
+                Err(invalid())
+            } else if let Ok(value) = v.parse::<i128>() {
+                self.visit_i128::<E>(value).map_err(|_| invalid())
+            } else if let Ok(value) = v.parse::<u128>() {
+                self.visit_u128::<E>(value).map_err(|_| invalid())
+            } else {
+                Err(invalid())
             }
         }
     }
 
-    deserializer.deserialize_any(UsizeOptVisitor)
+    deserializer.deserialize_any(IntegerOptVisitor::<T> {
+        _target: PhantomData,
+    })
 }
 
-pub fn serialize<S: Serializer>(value: &Option<usize>, serializer: S) -> Result<S::Ok, S::Error> {
+pub fn serialize<T: Serialize + PartialEq + TryFrom<i128>, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
     match value {
-        Some(value) => serializer.serialize_u64(*value as u64),
+        Some(value) if T::try_from(-1).is_ok_and(|sentinel| *value == sentinel) => Err(
+            serde::ser::Error::custom("-1 indicates absence and can't be serialized as a value"),
+        ),
+        Some(value) => value.serialize(serializer),
         None => serializer.serialize_i64(-1),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "super")]
+        small: Option<u8>,
+        #[serde(with = "super")]
+        signed: Option<i32>,
+        #[serde(with = "super")]
+        large: Option<u128>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"small":"255","signed":-2,"large":"340282366920938463463374607431768211455"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                small: Some(255),
+                signed: Some(-2),
+                large: Some(u128::MAX),
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip_json_absent() {
+        for json in [
+            r#"{"small":-1,"signed":"-1","large":-1}"#,
+            r#"{"small":"-1","signed":-1,"large":"-1"}"#,
+        ] {
+            let test = serde_json::from_str::<Test>(json).unwrap();
+
+            assert_eq!(
+                test,
+                Test {
+                    small: None,
+                    signed: None,
+                    large: None,
+                }
+            );
+            assert_eq!(
+                serde_json::to_string(&test).unwrap(),
+                r#"{"small":-1,"signed":-1,"large":-1}"#
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_json_sentinel() {
+        let test = Test {
+            small: None,
+            signed: Some(-1),
+            large: None,
+        };
+
+        assert!(serde_json::to_string(&test).is_err());
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"small":256,"signed":1,"large":1}"#,
+            r#"{"small":"256","signed":1,"large":1}"#,
+            r#"{"small":-2,"signed":1,"large":1}"#,
+            r#"{"small":1,"signed":" 1","large":1}"#,
+            r#"{"small":"+5","signed":1,"large":1}"#,
+            r#"{"small":1,"signed":"+1","large":1}"#,
+            r#"{"small":1,"signed":1,"large":1.5}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Deserialize a percentage number like `12.5` into the fraction it represents (and the reverse).
+//!
+//! Numbers are interpreted as in `ratio_i64`.
+
+use num_rational::Ratio;
+use num_traits::{CheckedDiv, CheckedMul};
+use serde::{de::Deserializer, ser::Serializer};
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ratio<i64>, D::Error> {
+    let value = super::ratio_i64::deserialize(deserializer)?;
+
+    value
+        .checked_div(&Ratio::from_integer(100))
+        .ok_or_else(|| serde::de::Error::custom("percentage cannot be represented as i64 ratio"))
+}
+
+pub fn serialize<S: Serializer>(value: &Ratio<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+    let value = value
+        .checked_mul(&Ratio::from_integer(100))
+        .ok_or_else(|| {
+            serde::ser::Error::custom("i64 ratio cannot be represented as percentage")
+        })?;
+
+    super::ratio_i64::serialize(&value, serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::percent;
+    use num_rational::Ratio;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "percent")]
+        foo: Ratio<i64>,
+        #[serde(with = "percent")]
+        bar: Ratio<i64>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(r#"{ "foo": 12.5, "bar": 3 }"#).unwrap();
+
+        assert_eq!(test.foo, Ratio::new(1, 8));
+        assert_eq!(test.bar, Ratio::new(3, 100));
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = Test {
+            foo: Ratio::new(1, 8),
+            bar: Ratio::new(3, 100),
+        };
+
+        let serialized = serde_json::json!(value).to_string();
+        let deserialized = serde_json::from_str::<Test>(&serialized).unwrap();
+
+        assert_eq!(serialized, r#"{"bar":3.0,"foo":12.5}"#);
+        assert_eq!(deserialized, value);
+    }
+}
@@ -0,0 +1,89 @@
+//! Deserialize a percentage string like `"12.5%"` into the fraction it represents (and the
+//! reverse).
+
+use num_rational::Ratio;
+use num_traits::{CheckedDiv, CheckedMul};
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+
+const EXPECTED: &str = "percentage string";
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Ratio<i64>, D::Error> {
+    struct PercentStrVisitor;
+
+    impl<'de> Visitor<'de> for PercentStrVisitor {
+        type Value = Ratio<i64>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            self.visit_str(v)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.strip_suffix('%')
+                .and_then(super::ratio_str::parse_decimal::<i64>)
+                .and_then(|value| value.checked_div(&Ratio::from_integer(100)))
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+    }
+
+    deserializer.deserialize_str(PercentStrVisitor)
+}
+
+pub fn serialize<S: Serializer>(value: &Ratio<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+    let text = value
+        .checked_mul(&Ratio::from_integer(100))
+        .and_then(|value| super::ratio_str::format_decimal(&value))
+        .ok_or_else(|| {
+            serde::ser::Error::custom("i64 ratio cannot be represented as a percentage string")
+        })?;
+
+    serializer.serialize_str(&format!("{text}%"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::percent_str;
+    use num_rational::Ratio;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "percent_str")]
+        foo: Ratio<i64>,
+        #[serde(with = "percent_str")]
+        bar: Ratio<i64>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(r#"{ "foo": "12.5%", "bar": "-250%" }"#).unwrap();
+
+        assert_eq!(test.foo, Ratio::new(1, 8));
+        assert_eq!(test.bar, Ratio::new(-5, 2));
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        assert!(serde_json::from_str::<Test>(r#"{ "foo": "12.5", "bar": "1%" }"#).is_err());
+        assert!(serde_json::from_str::<Test>(r#"{ "foo": "%", "bar": "1%" }"#).is_err());
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = Test {
+            foo: Ratio::new(1, 8),
+            bar: Ratio::new(-5, 2),
+        };
+
+        let serialized = serde_json::json!(value).to_string();
+        let deserialized = serde_json::from_str::<Test>(&serialized).unwrap();
+
+        assert_eq!(serialized, r#"{"bar":"-250%","foo":"12.5%"}"#);
+        assert_eq!(deserialized, value);
+    }
+}
@@ -0,0 +1,162 @@
+//! Integers represented as strings whose radix is indicated by a prefix (`"0x1f"`, `"0o17"`,
+//! `"0b1010"`, or `"42"` for decimal).
+//!
+//! The prefix is case-insensitive, and a leading `-` is accepted for signed types. The module-level
+//! functions serialize as lower-case hexadecimal. Another radix (2, 8, 10 or 16) can be chosen with
+//! [`PrefixedIntegerStr`] (e.g. `PrefixedIntegerStr::<2>`). The radix parameter only determines
+//! the serialized form, since the prefix always indicates the radix when deserializing.
+
+use crate::adapter::{DeserializeAdapter, SerializeAdapter};
+use crate::radix_str::{RadixExpected, parse_digits, serialize_radix, split_sign};
+use num_traits::{Num, PrimInt};
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::marker::PhantomData;
+
+const EXPECTED: &str = "prefixed integer string";
+
+pub struct PrefixedIntegerStr<const RADIX: u32 = 16, const UPPERCASE: bool = false>;
+
+impl<const RADIX: u32, const UPPERCASE: bool> PrefixedIntegerStr<RADIX, UPPERCASE> {
+    const VALID_RADIX: () = assert!(
+        RADIX == 2 || RADIX == 8 || RADIX == 10 || RADIX == 16,
+        "radix must be 2, 8, 10 or 16"
+    );
+
+    pub fn deserialize<'de, T: Num, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        struct PrefixedIntegerStrVisitor<T> {
+            _target: PhantomData<T>,
+        }
+
+        impl<T: Num> Visitor<'_> for PrefixedIntegerStrVisitor<T> {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str(EXPECTED)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                parse_prefixed(v)
+                    .map_err(|expected| E::invalid_value(Unexpected::Str(v), &expected))
+            }
+        }
+
+        let () = Self::VALID_RADIX;
+
+        deserializer.deserialize_str(PrefixedIntegerStrVisitor {
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<T: PrimInt, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let () = Self::VALID_RADIX;
+
+        serialize_radix(value, serializer, RADIX, true, UPPERCASE)
+    }
+}
+
+impl<'de, const RADIX: u32, const UPPERCASE: bool, T: Num> DeserializeAdapter<'de, T>
+    for PrefixedIntegerStr<RADIX, UPPERCASE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<const RADIX: u32, const UPPERCASE: bool, T: PrimInt> SerializeAdapter<T>
+    for PrefixedIntegerStr<RADIX, UPPERCASE>
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub fn deserialize<'de, T: Num, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    PrefixedIntegerStr::<16>::deserialize(deserializer)
+}
+
+pub fn serialize<T: PrimInt, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedIntegerStr::<16>::serialize(value, serializer)
+}
+
+fn parse_prefixed<T: Num>(value: &str) -> Result<T, RadixExpected> {
+    let (negative, rest) = split_sign(value);
+
+    let (radix, digits) = match rest.get(..2) {
+        Some("0x" | "0X") => (16, &rest[2..]),
+        Some("0o" | "0O") => (8, &rest[2..]),
+        Some("0b" | "0B") => (2, &rest[2..]),
+        _ => (10, rest),
+    };
+
+    parse_digits(negative, digits, radix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrefixedIntegerStr;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::prefixed_integer_str")]
+        value: i64,
+        #[serde(with = "PrefixedIntegerStr::<2>")]
+        binary: u8,
+        #[serde(with = "crate::optional_prefixed_integer_str")]
+        optional: Option<u32>,
+        #[serde(with = "crate::prefixed_integer_str_array")]
+        values: Vec<u16>,
+        #[serde(with = "crate::optional_prefixed_integer_str_array")]
+        optional_values: Option<Vec<u16>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"value":"-0X1F","binary":"0o17","optional":"42","values":["0b1010","10"],"optional_values":["0xabc"]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                value: -31,
+                binary: 15,
+                optional: Some(42),
+                values: vec![10, 10],
+                optional_values: Some(vec![2748]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"value":"-0x1f","binary":"0b1111","optional":"0x2a","values":["0xa","0xa"],"optional_values":["0xabc"]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"value":"0x","binary":"0","optional":null,"values":[],"optional_values":null}"#,
+                "invalid value: string \"0x\", expected base-16 integer string",
+            ),
+            (
+                r#"{"value":"0","binary":"0b100000000","optional":null,"values":[],"optional_values":null}"#,
+                "invalid value: string \"0b100000000\", expected base-2 integer string within the range of the target type",
+            ),
+            (
+                r#"{"value":"0","binary":"0","optional":"0o8","values":[],"optional_values":null}"#,
+                "invalid value: string \"0o8\", expected base-8 integer string",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! Integers represented as strings in an arbitrary radix (e.g. `"1f"` or `"0x1f"` for 31).
+//!
+//! [`RadixStr`] is parameterized by the radix (2 to 36), whether to write a prefix (`0b`, `0o` or
+//! `0x`, for radixes 2, 8 and 16) when serializing, and whether to write digits in upper case. The
+//! prefix is always optional when deserializing, and a leading `-` is accepted for signed types.
+//!
+//! Optional and array variants can be expressed with the generic combinators:
+//!
+//! ```
+//! use serde_field_attributes::{array::Array, optional::Optional, radix_str::RadixStr};
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Permissions {
+//!     #[serde(with = "RadixStr::<8>")]
+//!     mode: u32,
+//!     #[serde(with = "Optional::<RadixStr<2, true>>")]
+//!     flags: Option<u8>,
+//!     #[serde(with = "Array::<RadixStr<36>>")]
+//!     codes: Vec<u64>,
+//! }
+//! ```
+
+use crate::adapter::{DeserializeAdapter, SerializeAdapter};
+use num_traits::{Num, PrimInt};
+use serde::{
+    de::{Deserializer, Expected, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::marker::PhantomData;
+
+pub struct RadixStr<const RADIX: u32, const PREFIX: bool = false, const UPPERCASE: bool = false>;
+
+impl<const RADIX: u32, const PREFIX: bool, const UPPERCASE: bool>
+    RadixStr<RADIX, PREFIX, UPPERCASE>
+{
+    const VALID_RADIX: () = assert!(RADIX >= 2 && RADIX <= 36, "radix must be between 2 and 36");
+
+    pub fn deserialize<'de, T: Num, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let () = Self::VALID_RADIX;
+
+        deserialize_radix(deserializer, RADIX)
+    }
+
+    pub fn serialize<T: PrimInt, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let () = Self::VALID_RADIX;
+
+        serialize_radix(value, serializer, RADIX, PREFIX, UPPERCASE)
+    }
+}
+
+impl<'de, const RADIX: u32, const PREFIX: bool, const UPPERCASE: bool, T: Num>
+    DeserializeAdapter<'de, T> for RadixStr<RADIX, PREFIX, UPPERCASE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<const RADIX: u32, const PREFIX: bool, const UPPERCASE: bool, T: PrimInt> SerializeAdapter<T>
+    for RadixStr<RADIX, PREFIX, UPPERCASE>
+{
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+/// The expected value for a radix, with an indication of whether the input overflowed.
+pub(crate) struct RadixExpected {
+    pub radix: u32,
+    pub overflow: bool,
+}
+
+impl Expected for RadixExpected {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "base-{} integer string", self.radix)?;
+
+        if self.overflow {
+            formatter.write_str(" within the range of the target type")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The standard prefix for a radix, if there is one.
+pub(crate) const fn prefix(radix: u32) -> Option<&'static str> {
+    match radix {
+        2 => Some("0b"),
+        8 => Some("0o"),
+        16 => Some("0x"),
+        _ => None,
+    }
+}
+
+/// Split a string into a sign and the remaining part.
+pub(crate) fn split_sign(value: &str) -> (bool, &str) {
+    value
+        .strip_prefix('-')
+        .map_or_else(|| (false, value), |rest| (true, rest))
+}
+
+/// Parse unprefixed digits (with an optional sign) in the given radix.
+pub(crate) fn parse_digits<T: Num>(
+    negative: bool,
+    digits: &str,
+    radix: u32,
+) -> Result<T, RadixExpected> {
+    let expected = |overflow| RadixExpected { radix, overflow };
+
+    // `from_str_radix` accepts a sign, which we've already handled.
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(expected(false));
+    }
+
+    let result = if negative {
+        T::from_str_radix(&format!("-{digits}"), radix)
+    } else {
+        T::from_str_radix(digits, radix)
+    };
+
+    // The digits are valid, so any failure must be due to overflow.
+    result.map_err(|_| expected(true))
+}
+
+/// Parse a string in the given radix, with an optional standard prefix.
+pub(crate) fn parse_radix<T: Num>(value: &str, radix: u32) -> Result<T, RadixExpected> {
+    let (negative, rest) = split_sign(value);
+
+    let digits = prefix(radix)
+        .and_then(|prefix| {
+            rest.strip_prefix(prefix)
+                .or_else(|| rest.strip_prefix(&prefix.to_uppercase()))
+        })
+        .unwrap_or(rest);
+
+    parse_digits(negative, digits, radix)
+}
+
+/// Format an integer in the given radix (which must be between 2 and 36).
+pub(crate) fn format_radix<T: PrimInt>(
+    value: &T,
+    radix: u32,
+    with_prefix: bool,
+    uppercase: bool,
+) -> Option<String> {
+    let (negative, mut magnitude) = match value.to_u128() {
+        Some(magnitude) => (false, magnitude),
+        None => (true, value.to_i128()?.unsigned_abs()),
+    };
+
+    let mut digits = vec![];
+
+    loop {
+        let digit = char::from_digit(u32::try_from(magnitude % u128::from(radix)).ok()?, radix)?;
+        digits.push(if uppercase {
+            digit.to_ascii_uppercase()
+        } else {
+            digit
+        });
+        magnitude /= u128::from(radix);
+
+        if magnitude == 0 {
+            break;
+        }
+    }
+
+    let mut result = String::with_capacity(digits.len() + 3);
+
+    if negative {
+        result.push('-');
+    }
+
+    if with_prefix && let Some(prefix) = prefix(radix) {
+        result.push_str(prefix);
+    }
+
+    result.extend(digits.into_iter().rev());
+
+    Some(result)
+}
+
+pub(crate) fn deserialize_radix<'de, T: Num, D: Deserializer<'de>>(
+    deserializer: D,
+    radix: u32,
+) -> Result<T, D::Error> {
+    struct RadixStrVisitor<T> {
+        radix: u32,
+        _target: PhantomData<T>,
+    }
+
+    impl<T: Num> Visitor<'_> for RadixStrVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            Expected::fmt(
+                &RadixExpected {
+                    radix: self.radix,
+                    overflow: false,
+                },
+                formatter,
+            )
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            parse_radix(v, self.radix)
+                .map_err(|expected| E::invalid_value(Unexpected::Str(v), &expected))
+        }
+    }
+
+    deserializer.deserialize_str(RadixStrVisitor {
+        radix,
+        _target: PhantomData,
+    })
+}
+
+pub(crate) fn serialize_radix<T: PrimInt, S: Serializer>(
+    value: &T,
+    serializer: S,
+    radix: u32,
+    with_prefix: bool,
+    uppercase: bool,
+) -> Result<S::Ok, S::Error> {
+    let formatted = format_radix(value, radix, with_prefix, uppercase).ok_or_else(|| {
+        serde::ser::Error::custom("integer cannot be represented as a 128-bit integer")
+    })?;
+
+    serializer.serialize_str(&formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RadixStr;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "RadixStr::<2, true>")]
+        binary: u8,
+        #[serde(with = "RadixStr::<8>")]
+        octal: i32,
+        #[serde(with = "RadixStr::<16, true, true>")]
+        hex: i64,
+        #[serde(with = "RadixStr::<36>")]
+        base36: u128,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"binary":"1010","octal":"-0o17","hex":"0xff","base36":"zz"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                binary: 10,
+                octal: -15,
+                hex: 255,
+                base36: 1295,
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"binary":"0b1010","octal":"-17","hex":"0xFF","base36":"zz"}"#
+        );
+    }
+
+    #[test]
+    fn serialize_extremes() {
+        let test = Test {
+            binary: 0,
+            octal: i32::MIN,
+            hex: i64::MIN,
+            base36: u128::MAX,
+        };
+        let json = serde_json::to_string(&test).unwrap();
+
+        assert_eq!(serde_json::from_str::<Test>(&json).unwrap(), test);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"binary":"100000000","octal":"0","hex":"0","base36":"0"}"#,
+                "invalid value: string \"100000000\", expected base-2 integer string within the range of the target type",
+            ),
+            (
+                r#"{"binary":"2","octal":"0","hex":"0","base36":"0"}"#,
+                "invalid value: string \"2\", expected base-2 integer string",
+            ),
+            (
+                r#"{"binary":"0","octal":"+1","hex":"0","base36":"0"}"#,
+                "invalid value: string \"+1\", expected base-8 integer string",
+            ),
+            (
+                r#"{"binary":"0","octal":"0","hex":"0x","base36":"0"}"#,
+                "invalid value: string \"0x\", expected base-16 integer string",
+            ),
+            (
+                r#"{"binary":"0","octal":"0","hex":"0","base36":"-1"}"#,
+                "invalid value: string \"-1\", expected base-36 integer string within the range of the target type",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
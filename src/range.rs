@@ -1,23 +1,182 @@
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, SerializeTuple, Serializer},
 };
+use std::fmt::Display;
+use std::marker::PhantomData;
 use std::ops::Range;
 
 pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<Range<T>, D::Error> {
-    let (start, end) = Deserialize::deserialize(deserializer)?;
-
-    Ok(start..end)
+    With::<Plain>::deserialize(deserializer)
 }
 
 pub fn serialize<T: Serialize, S: Serializer>(
     value: &Range<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
-    let mut tuple = serializer.serialize_tuple(2)?;
-    tuple.serialize_element(&value.start)?;
-    tuple.serialize_element(&value.end)?;
-    tuple.end()
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// A range whose endpoints are deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<Range<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end): (Deserializable<A, T>, Deserializable<A, T>) =
+            Deserialize::deserialize(deserializer)?;
+
+        Ok(start.value..end.value)
+    }
+
+    pub fn serialize<T, S: Serializer>(value: &Range<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Serializable::<A, T>::new(&value.start))?;
+        tuple.serialize_element(&Serializable::<A, T>::new(&value.end))?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, Range<T>> for With<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Range<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<T>, T> SerializeAdapter<Range<T>> for With<A> {
+    fn serialize<S: Serializer>(value: &Range<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+/// Reject ranges whose start is greater than their end.
+pub mod checked {
+    use serde::de::{Deserialize, Deserializer};
+    use std::fmt::Display;
+    use std::ops::Range;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: Deserialize<'de> + PartialOrd + Display, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Range<T>, D::Error> {
+        let range = super::deserialize(deserializer)?;
+
+        super::validate(&range, true).map_err(serde::de::Error::custom)?;
+
+        Ok(range)
+    }
+}
+
+/// Reject ranges whose start is greater than or equal to their end.
+pub mod non_empty {
+    use serde::de::{Deserialize, Deserializer};
+    use std::fmt::Display;
+    use std::ops::Range;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: Deserialize<'de> + PartialOrd + Display, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Range<T>, D::Error> {
+        let range = super::deserialize(deserializer)?;
+
+        super::validate(&range, false).map_err(serde::de::Error::custom)?;
+
+        Ok(range)
+    }
+}
+
+pub(crate) fn validate<T: PartialOrd + Display>(
+    range: &Range<T>,
+    allow_empty: bool,
+) -> Result<(), String> {
+    if range.start > range.end {
+        Err(format!(
+            "invalid range: start {} is greater than end {}",
+            range.start, range.end
+        ))
+    } else if !allow_empty && range.start == range.end {
+        Err(format!(
+            "invalid range: start {} is equal to end {}",
+            range.start, range.end
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter;
+    use chrono::{DateTime, Utc};
+    use std::ops::Range;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range::checked")]
+        checked: Range<i32>,
+        #[serde(with = "crate::range::non_empty")]
+        non_empty: Range<i32>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(r#"{"checked":[3,3],"non_empty":[3,4]}"#).unwrap();
+
+        assert_eq!(test.checked, 3..3);
+        assert_eq!(test.non_empty, 3..4);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        let reversed = serde_json::from_str::<Test>(r#"{"checked":[5,1],"non_empty":[3,4]}"#)
+            .unwrap_err()
+            .to_string();
+        let empty = serde_json::from_str::<Test>(r#"{"checked":[1,5],"non_empty":[3,3]}"#)
+            .unwrap_err()
+            .to_string();
+
+        assert!(reversed.starts_with("invalid range: start 5 is greater than end 1"));
+        assert!(empty.starts_with("invalid range: start 3 is equal to end 3"));
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct AdaptedTest {
+        #[serde(with = "crate::range::With::<adapter::TimestampStr>")]
+        window: Range<DateTime<Utc>>,
+        #[serde(with = "crate::range::With::<adapter::IntegerStr>")]
+        ids: Range<u64>,
+    }
+
+    #[test]
+    fn round_trip_json_adapted() {
+        let json = r#"{"window":["1609459200","1609545600"],"ids":["1","18446744073709551615"]}"#;
+        let value = serde_json::from_str::<AdaptedTest>(json).unwrap();
+
+        assert_eq!(
+            value.window,
+            DateTime::from_timestamp(1_609_459_200, 0).unwrap()
+                ..DateTime::from_timestamp(1_609_545_600, 0).unwrap()
+        );
+        assert_eq!(value.ids, 1..u64::MAX);
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_adapted_invalid() {
+        let json = r#"{"window":[1609459200,1609545600],"ids":["1","2"]}"#;
+
+        assert!(serde_json::from_str::<AdaptedTest>(json).is_err());
+    }
 }
@@ -0,0 +1,123 @@
+//! A pair of bounds, represented as a pair where each element is either null (for an unbounded
+//! end) or an externally tagged `Included` or `Excluded` value (e.g. `[{"Included": 3}, null]`).
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+type Endpoints<A, T> = (
+    Option<Bound<Deserializable<A, T>>>,
+    Option<Bound<Deserializable<A, T>>>,
+);
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(Bound<T>, Bound<T>), D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &(Bound<T>, Bound<T>),
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// A pair of bounds whose values are deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(Bound<T>, Bound<T>), D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end): Endpoints<A, T> = Deserialize::deserialize(deserializer)?;
+
+        Ok((unbounded_if_none(start), unbounded_if_none(end)))
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &(Bound<T>, Bound<T>),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&none_if_unbounded::<A, T>(&value.0))?;
+        tuple.serialize_element(&none_if_unbounded::<A, T>(&value.1))?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, (Bound<T>, Bound<T>)>
+    for With<A>
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(Bound<T>, Bound<T>), D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<T>, T> SerializeAdapter<(Bound<T>, Bound<T>)> for With<A> {
+    fn serialize<S: Serializer>(
+        value: &(Bound<T>, Bound<T>),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub(crate) fn unbounded_if_none<A, T>(bound: Option<Bound<Deserializable<A, T>>>) -> Bound<T> {
+    bound.map_or(Bound::Unbounded, |bound| bound.map(|value| value.value))
+}
+
+pub(crate) fn none_if_unbounded<A, T>(bound: &Bound<T>) -> Option<Bound<Serializable<'_, A, T>>> {
+    match bound {
+        Bound::Unbounded => None,
+        bound => Some(bound.as_ref().map(Serializable::new)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_bounds")]
+        value: (Bound<u32>, Bound<u32>),
+    }
+
+    #[test]
+    fn round_trip_json() {
+        for (json, expected) in [
+            (
+                r#"{"value":[{"Included":3},null]}"#,
+                (Bound::Included(3), Bound::Unbounded),
+            ),
+            (
+                r#"{"value":[{"Excluded":3},{"Included":5}]}"#,
+                (Bound::Excluded(3), Bound::Included(5)),
+            ),
+            (
+                r#"{"value":[null,null]}"#,
+                (Bound::Unbounded, Bound::Unbounded),
+            ),
+        ] {
+            let value = serde_json::from_str::<Test>(json).unwrap();
+
+            assert_eq!(value.value, expected);
+            assert_eq!(serde_json::json!(value).to_string(), json);
+        }
+    }
+}
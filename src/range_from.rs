@@ -0,0 +1,102 @@
+//! A range with no end, represented as a pair with a null end (e.g. `[3, null]`).
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer, IgnoredAny, Unexpected},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::RangeFrom;
+
+const EXPECTED: &str = "range with null end";
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RangeFrom<T>, D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &RangeFrom<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// A range whose start is deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RangeFrom<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end): (Deserializable<A, T>, Option<IgnoredAny>) =
+            Deserialize::deserialize(deserializer)?;
+
+        if end.is_some() {
+            Err(serde::de::Error::invalid_value(
+                Unexpected::Other("bounded end"),
+                &EXPECTED,
+            ))
+        } else {
+            Ok(start.value..)
+        }
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &RangeFrom<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Serializable::<A, T>::new(&value.start))?;
+        tuple.serialize_element(&None::<()>)?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, RangeFrom<T>> for With<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RangeFrom<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<T>, T> SerializeAdapter<RangeFrom<T>> for With<A> {
+    fn serialize<S: Serializer>(value: &RangeFrom<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeFrom;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_from")]
+        value: RangeFrom<u32>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = serde_json::from_str::<Test>(r#"{"value":[3,null]}"#).unwrap();
+
+        assert_eq!(value.value, 3..);
+        assert_eq!(
+            serde_json::json!(value).to_string(),
+            r#"{"value":[3,null]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_bounded_end() {
+        assert!(serde_json::from_str::<Test>(r#"{"value":[3,5]}"#).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RangeInclusive<T>, D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &RangeInclusive<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// A range whose endpoints are deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RangeInclusive<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end): (Deserializable<A, T>, Deserializable<A, T>) =
+            Deserialize::deserialize(deserializer)?;
+
+        Ok(start.value..=end.value)
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Serializable::<A, T>::new(value.start()))?;
+        tuple.serialize_element(&Serializable::<A, T>::new(value.end()))?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, RangeInclusive<T>> for With<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RangeInclusive<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<T>, T> SerializeAdapter<RangeInclusive<T>> for With<A> {
+    fn serialize<S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_inclusive")]
+        value: RangeInclusive<u32>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = serde_json::from_str::<Test>(r#"{"value":[1,5]}"#).unwrap();
+
+        assert_eq!(value.value, 1..=5);
+        assert_eq!(serde_json::json!(value).to_string(), r#"{"value":[1,5]}"#);
+    }
+}
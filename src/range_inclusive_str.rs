@@ -0,0 +1,140 @@
+//! Deserialize an inclusive range from a string like `"10..=20"`, `"10-20"` or `"[10,20]"` (and
+//! the reverse).
+//!
+//! Endpoints are parsed with `FromStr`. Strings using half-open notation (such as `"10..20"` or
+//! `"[10,20)"`) are rejected; see `range_str` for those.
+
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+const EXPECTED: &str = "inclusive range string";
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RangeInclusive<T>, D::Error> {
+    struct RangeInclusiveStrVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: FromStr> Visitor<'de> for RangeInclusiveStrVisitor<T> {
+        type Value = RangeInclusive<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            self.visit_str(v)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            super::range_str::parse_range(v)
+                .filter(|range| range.start_inclusive && range.end_inclusive)
+                .map(|range| range.start..=range.end)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+    }
+
+    deserializer.deserialize_str(RangeInclusiveStrVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+/// Serialize using Rust range notation (e.g. `"10..=20"`).
+pub fn serialize<T: Display, S: Serializer>(
+    value: &RangeInclusive<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("{}..={}", value.start(), value.end()))
+}
+
+/// Serialize using dash notation (e.g. `"10-20"`).
+pub mod dash {
+    use serde::ser::Serializer;
+    use std::fmt::Display;
+    use std::ops::RangeInclusive;
+
+    pub use super::deserialize;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{}-{}", value.start(), value.end()))
+    }
+}
+
+/// Serialize using interval notation (e.g. `"[10,20]"`).
+pub mod interval {
+    use serde::ser::Serializer;
+    use std::fmt::Display;
+    use std::ops::RangeInclusive;
+
+    pub use super::deserialize;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("[{},{}]", value.start(), value.end()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_inclusive_str")]
+        value: RangeInclusive<i32>,
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct DashTest {
+        #[serde(with = "crate::range_inclusive_str::dash")]
+        value: RangeInclusive<i32>,
+    }
+
+    #[test]
+    fn parse_json() {
+        for (json, expected) in [
+            (r#"{"value":"10..=20"}"#, 10..=20),
+            (r#"{"value":"10-20"}"#, 10..=20),
+            (r#"{"value":"-20--10"}"#, -20..=-10),
+            (r#"{"value":"-20-10"}"#, -20..=10),
+            (r#"{"value":"[10,20]"}"#, 10..=20),
+        ] {
+            assert_eq!(serde_json::from_str::<Test>(json).unwrap().value, expected);
+        }
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"value":"10..20"}"#,
+            r#"{"value":"[10,20)"}"#,
+            r#"{"value":"10--"}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn serialize_json() {
+        assert_eq!(
+            serde_json::json!(Test { value: -20..=10 }).to_string(),
+            r#"{"value":"-20..=10"}"#
+        );
+        assert_eq!(
+            serde_json::json!(DashTest { value: 10..=20 }).to_string(),
+            r#"{"value":"10-20"}"#
+        );
+    }
+}
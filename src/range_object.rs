@@ -0,0 +1,276 @@
+//! Ranges represented as objects with configurable field names (e.g. `{"start": 1, "end": 5}`).
+//!
+//! The module-level functions use `start` and `end`. Other field names can be used with
+//! [`RangeObject`] and [`RangeInclusiveObject`], either with one of the provided [`FieldNames`]
+//! implementations or a custom one:
+//!
+//! ```
+//! use serde_field_attributes::range_object::{MinMax, RangeInclusiveObject};
+//! use std::ops::RangeInclusive;
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Config {
+//!     #[serde(with = "RangeInclusiveObject::<MinMax>")]
+//!     page_size: RangeInclusive<u32>,
+//! }
+//! ```
+//!
+//! Like the tuple-form range modules, the endpoints can also be deserialized and serialized with
+//! an element adapter (e.g. `RangeObject::<StartEnd, adapter::IntegerStr>`).
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+
+const EXPECTED: &str = "range object";
+
+/// The field names used for the endpoints of a range.
+pub trait FieldNames {
+    const START: &'static str;
+    const END: &'static str;
+}
+
+/// `{"start": ..., "end": ...}`
+pub struct StartEnd;
+
+impl FieldNames for StartEnd {
+    const START: &'static str = "start";
+    const END: &'static str = "end";
+}
+
+/// `{"min": ..., "max": ...}`
+pub struct MinMax;
+
+impl FieldNames for MinMax {
+    const START: &'static str = "min";
+    const END: &'static str = "max";
+}
+
+/// `{"from": ..., "to": ...}`
+pub struct FromTo;
+
+impl FieldNames for FromTo {
+    const START: &'static str = "from";
+    const END: &'static str = "to";
+}
+
+pub struct RangeObject<N = StartEnd, A = Plain> {
+    _names: PhantomData<N>,
+    _adapter: PhantomData<A>,
+}
+
+impl<N: FieldNames, A> RangeObject<N, A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<Range<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end) = deserialize_endpoints::<N, A, T, D>(deserializer)?;
+
+        Ok(start..end)
+    }
+
+    pub fn serialize<T, S: Serializer>(value: &Range<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        serialize_endpoints::<N, A, T, S>(&value.start, &value.end, serializer)
+    }
+}
+
+impl<'de, N: FieldNames, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, Range<T>>
+    for RangeObject<N, A>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Range<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<N: FieldNames, A: SerializeAdapter<T>, T> SerializeAdapter<Range<T>> for RangeObject<N, A> {
+    fn serialize<S: Serializer>(value: &Range<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub struct RangeInclusiveObject<N = StartEnd, A = Plain> {
+    _names: PhantomData<N>,
+    _adapter: PhantomData<A>,
+}
+
+impl<N: FieldNames, A> RangeInclusiveObject<N, A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RangeInclusive<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end) = deserialize_endpoints::<N, A, T, D>(deserializer)?;
+
+        Ok(start..=end)
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        serialize_endpoints::<N, A, T, S>(value.start(), value.end(), serializer)
+    }
+}
+
+impl<'de, N: FieldNames, A: DeserializeAdapter<'de, T>, T>
+    DeserializeAdapter<'de, RangeInclusive<T>> for RangeInclusiveObject<N, A>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RangeInclusive<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<N: FieldNames, A: SerializeAdapter<T>, T> SerializeAdapter<RangeInclusive<T>>
+    for RangeInclusiveObject<N, A>
+{
+    fn serialize<S: Serializer>(
+        value: &RangeInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Range<T>, D::Error> {
+    RangeObject::<StartEnd>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &Range<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    RangeObject::<StartEnd>::serialize(value, serializer)
+}
+
+pub(crate) fn deserialize_endpoints<
+    'de,
+    N: FieldNames,
+    A: DeserializeAdapter<'de, T>,
+    T,
+    D: Deserializer<'de>,
+>(
+    deserializer: D,
+) -> Result<(T, T), D::Error> {
+    struct RangeObjectVisitor<N, A, T> {
+        _names: PhantomData<N>,
+        _adapter: PhantomData<A>,
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, N: FieldNames, A: DeserializeAdapter<'de, T>, T> Visitor<'de>
+        for RangeObjectVisitor<N, A, T>
+    {
+        type Value = (T, T);
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+            let mut start = None;
+            let mut end = None;
+
+            while let Some(key) = map.next_key::<Cow<'_, str>>()? {
+                if key == N::START {
+                    if start.is_some() {
+                        return Err(serde::de::Error::duplicate_field(N::START));
+                    }
+                    start = Some(map.next_value::<Deserializable<A, T>>()?.value);
+                } else if key == N::END {
+                    if end.is_some() {
+                        return Err(serde::de::Error::duplicate_field(N::END));
+                    }
+                    end = Some(map.next_value::<Deserializable<A, T>>()?.value);
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+
+            Ok((
+                start.ok_or_else(|| serde::de::Error::missing_field(N::START))?,
+                end.ok_or_else(|| serde::de::Error::missing_field(N::END))?,
+            ))
+        }
+    }
+
+    deserializer.deserialize_map(RangeObjectVisitor::<N, A, T> {
+        _names: PhantomData,
+        _adapter: PhantomData,
+        _target: PhantomData,
+    })
+}
+
+pub(crate) fn serialize_endpoints<N: FieldNames, A: SerializeAdapter<T>, T, S: Serializer>(
+    start: &T,
+    end: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut object = serializer.serialize_struct("Range", 2)?;
+    object.serialize_field(N::START, &Serializable::<A, T>::new(start))?;
+    object.serialize_field(N::END, &Serializable::<A, T>::new(end))?;
+    object.end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MinMax, RangeInclusiveObject};
+    use std::ops::{Range, RangeInclusive};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_object")]
+        range: Range<u32>,
+        #[serde(with = "RangeInclusiveObject::<MinMax>")]
+        limits: RangeInclusive<u32>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"range":{"end":5,"start":1,"step":2},"limits":{"min":10,"max":20}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(test.range, 1..5);
+        assert_eq!(test.limits, 10..=20);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"range":{"start":1},"limits":{"min":10,"max":20}}"#,
+            r#"{"range":{"start":1,"end":5,"end":6},"limits":{"min":10,"max":20}}"#,
+            r#"{"range":{"start":1,"end":5},"limits":{"start":10,"end":20}}"#,
+            r#"{"range":[1,5],"limits":{"min":10,"max":20}}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn serialize_json() {
+        let value = Test {
+            range: 1..5,
+            limits: 10..=20,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{"range":{"start":1,"end":5},"limits":{"min":10,"max":20}}"#
+        );
+    }
+}
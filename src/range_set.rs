@@ -0,0 +1,276 @@
+//! Deserialize a sequence of ranges (in the `range` tuple format) into a normalized set of
+//! disjoint ranges (and the reverse).
+//!
+//! Empty ranges are dropped, and overlapping or adjacent ranges are merged. The `disjoint` module
+//! rejects overlapping ranges instead of merging them (adjacent ranges are still merged).
+
+use crate::adapter::{Deserializable, Plain, Serializable};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+use std::fmt::Debug;
+use std::ops::Range;
+
+type RangeAdapter = super::range::With<Plain>;
+
+/// A set of values represented as a sorted sequence of disjoint, non-adjacent, non-empty ranges.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+#[error("overlapping ranges: {first:?} and {second:?}")]
+pub struct OverlapError<T: Debug> {
+    pub first: Range<T>,
+    pub second: Range<T>,
+}
+
+impl<T> RangeSet<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Ord + Clone> RangeSet<T> {
+    /// Create a set from ranges that must not overlap.
+    pub fn try_from_disjoint<I: IntoIterator<Item = Range<T>>>(
+        ranges: I,
+    ) -> Result<Self, OverlapError<T>>
+    where
+        T: Debug,
+    {
+        normalize(ranges.into_iter().collect()).map(|ranges| Self { ranges })
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+
+        self.ranges = merge(ranges);
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= *value);
+
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= *value)
+    }
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self {
+            ranges: merge(iter.into_iter().collect()),
+        }
+    }
+}
+
+impl<T> IntoIterator for RangeSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = std::slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+pub fn deserialize<'de, T: Deserialize<'de> + Ord + Clone, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RangeSet<T>, D::Error> {
+    let ranges: Vec<Deserializable<RangeAdapter, Range<T>>> =
+        Deserialize::deserialize(deserializer)?;
+
+    Ok(ranges.into_iter().map(|range| range.value).collect())
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &RangeSet<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(value.ranges.len()))?;
+
+    for range in &value.ranges {
+        seq.serialize_element(&Serializable::<RangeAdapter, _>::new(range))?;
+    }
+
+    seq.end()
+}
+
+/// Reject overlapping ranges instead of merging them.
+pub mod disjoint {
+    use super::{RangeAdapter, RangeSet};
+    use crate::adapter::Deserializable;
+    use serde::de::{Deserialize, Deserializer};
+    use std::fmt::Debug;
+    use std::ops::Range;
+
+    pub use super::serialize;
+
+    pub fn deserialize<'de, T: Deserialize<'de> + Ord + Clone + Debug, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RangeSet<T>, D::Error> {
+        let ranges: Vec<Deserializable<RangeAdapter, Range<T>>> =
+            Deserialize::deserialize(deserializer)?;
+
+        RangeSet::try_from_disjoint(ranges.into_iter().map(|range| range.value))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+fn merge<T: Ord + Clone>(ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+    normalize_with(ranges, |_, _| Ok::<_, std::convert::Infallible>(()))
+        .unwrap_or_else(|error| match error {})
+}
+
+fn normalize<T: Ord + Clone + Debug>(
+    ranges: Vec<Range<T>>,
+) -> Result<Vec<Range<T>>, OverlapError<T>> {
+    normalize_with(ranges, |first, second| {
+        if second.start >= first.end {
+            Ok(())
+        } else {
+            Err(OverlapError {
+                first: first.clone(),
+                second: second.clone(),
+            })
+        }
+    })
+}
+
+/// Sort and merge ranges, calling `check` before merging a range into the previous ones, with the
+/// input range that the merged range currently ends with.
+fn normalize_with<T: Ord + Clone, E, F: Fn(&Range<T>, &Range<T>) -> Result<(), E>>(
+    mut ranges: Vec<Range<T>>,
+    check: F,
+) -> Result<Vec<Range<T>>, E> {
+    ranges.retain(|range| range.start < range.end);
+    ranges.sort_by(|a, b| a.start.cmp(&b.start));
+
+    let mut result: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    let mut last_input: Option<Range<T>> = None;
+
+    for range in ranges {
+        match (result.last_mut(), last_input.as_ref()) {
+            (Some(last), Some(input)) if range.start <= last.end => {
+                check(input, &range)?;
+
+                if range.end > last.end {
+                    last.end = range.end.clone();
+                    last_input = Some(range);
+                }
+            }
+            _ => {
+                last_input = Some(range.clone());
+                result.push(range);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSet;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_set")]
+        merged: RangeSet<u32>,
+        #[serde(with = "crate::range_set::disjoint")]
+        disjoint: RangeSet<u32>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"merged":[[10,20],[1,3],[15,25],[3,5],[7,7]],"disjoint":[[10,20],[1,3],[3,5]]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(test.merged.ranges(), &[1..5, 10..25]);
+        assert_eq!(test.disjoint.ranges(), &[1..5, 10..20]);
+    }
+
+    #[test]
+    fn parse_json_overlapping() {
+        let error =
+            serde_json::from_str::<Test>(r#"{"merged":[],"disjoint":[[10,20],[1,3],[15,25]]}"#)
+                .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("overlapping ranges: 10..20 and 15..25")
+        );
+
+        // The error reports the input ranges, not the ranges merged so far.
+        let error =
+            serde_json::from_str::<Test>(r#"{"merged":[],"disjoint":[[1,5],[5,10],[8,12]]}"#)
+                .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("overlapping ranges: 5..10 and 8..12")
+        );
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = Test {
+            merged: [5..8, 1..3, 2..4].into_iter().collect(),
+            disjoint: RangeSet::new(),
+        };
+        let serialized = serde_json::json!(value).to_string();
+
+        assert_eq!(serialized, r#"{"disjoint":[],"merged":[[1,4],[5,8]]}"#);
+        assert_eq!(serde_json::from_str::<Test>(&serialized).unwrap(), value);
+    }
+
+    #[test]
+    fn contains() {
+        let mut set = [1..3, 5..8].into_iter().collect::<RangeSet<u32>>();
+        set.insert(3..4);
+
+        assert_eq!(set.ranges(), &[1..4, 5..8]);
+        assert!(set.contains(&1));
+        assert!(set.contains(&3));
+        assert!(!set.contains(&4));
+        assert!(set.contains(&7));
+        assert!(!set.contains(&8));
+    }
+}
@@ -0,0 +1,179 @@
+//! Deserialize a half-open range from a string like `"10..20"` or `"[10,20)"` (and the reverse).
+//!
+//! Endpoints are parsed with `FromStr`. Strings using inclusive notation (such as `"10..=20"` or
+//! `"[10,20]"`) are rejected; see `range_inclusive_str` for those. This includes dash notation
+//! (`"10-20"`), which conventionally includes the end, and can't be converted to a half-open range
+//! for endpoint types without a successor (such as decimals).
+
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::str::FromStr;
+
+const EXPECTED: &str = "half-open range string";
+
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Range<T>, D::Error> {
+    struct RangeStrVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: FromStr> Visitor<'de> for RangeStrVisitor<T> {
+        type Value = Range<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            self.visit_str(v)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            parse_range(v)
+                .filter(|range| range.start_inclusive && !range.end_inclusive)
+                .map(|range| range.start..range.end)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+    }
+
+    deserializer.deserialize_str(RangeStrVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+/// Serialize using Rust range notation (e.g. `"10..20"`).
+pub fn serialize<T: Display, S: Serializer>(
+    value: &Range<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("{}..{}", value.start, value.end))
+}
+
+/// Serialize using interval notation (e.g. `"[10,20)"`).
+pub mod interval {
+    use serde::ser::Serializer;
+    use std::fmt::Display;
+    use std::ops::Range;
+
+    pub use super::deserialize;
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &Range<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("[{},{})", value.start, value.end))
+    }
+}
+
+pub(crate) struct ParsedRange<T> {
+    pub(crate) start: T,
+    pub(crate) end: T,
+    pub(crate) start_inclusive: bool,
+    pub(crate) end_inclusive: bool,
+}
+
+/// Parse a range in Rust (`"1..5"`, `"1..=5"`), dash (`"1-5"`), or interval (`"[1,5)"`) notation.
+///
+/// Dash notation is always inclusive. For dash notation, the first dash that results in two valid
+/// endpoints is used as the separator, which allows negative numbers (e.g. `"-5--1"`).
+pub(crate) fn parse_range<T: FromStr>(text: &str) -> Option<ParsedRange<T>> {
+    if let Some(inner) = text
+        .strip_prefix(['[', '('])
+        .and_then(|inner| inner.strip_suffix([']', ')']))
+    {
+        let (start, end) = inner.split_once(',')?;
+
+        Some(ParsedRange {
+            start: start.trim().parse().ok()?,
+            end: end.trim().parse().ok()?,
+            start_inclusive: text.starts_with('['),
+            end_inclusive: text.ends_with(']'),
+        })
+    } else if let Some((start, end)) = text.split_once("..=") {
+        Some(ParsedRange {
+            start: start.parse().ok()?,
+            end: end.parse().ok()?,
+            start_inclusive: true,
+            end_inclusive: true,
+        })
+    } else if let Some((start, end)) = text.split_once("..") {
+        Some(ParsedRange {
+            start: start.parse().ok()?,
+            end: end.parse().ok()?,
+            start_inclusive: true,
+            end_inclusive: false,
+        })
+    } else {
+        text.match_indices('-')
+            .filter(|(index, _)| *index > 0)
+            .find_map(|(index, _)| {
+                Some(ParsedRange {
+                    start: text[..index].parse().ok()?,
+                    end: text[index + 1..].parse().ok()?,
+                    start_inclusive: true,
+                    end_inclusive: true,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_str")]
+        value: Range<i32>,
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct IntervalTest {
+        #[serde(with = "crate::range_str::interval")]
+        value: Range<i32>,
+    }
+
+    #[test]
+    fn parse_json() {
+        for (json, expected) in [
+            (r#"{"value":"10..20"}"#, 10..20),
+            (r#"{"value":"-20..-10"}"#, -20..-10),
+            (r#"{"value":"[10,20)"}"#, 10..20),
+            (r#"{"value":"[10, 20)"}"#, 10..20),
+        ] {
+            assert_eq!(serde_json::from_str::<Test>(json).unwrap().value, expected);
+        }
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"value":"10..=20"}"#,
+            r#"{"value":"10-20"}"#,
+            r#"{"value":"[10,20]"}"#,
+            r#"{"value":"(10,20)"}"#,
+            r#"{"value":"10..x"}"#,
+            r#"{"value":"10"}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn serialize_json() {
+        assert_eq!(
+            serde_json::json!(Test { value: 10..20 }).to_string(),
+            r#"{"value":"10..20"}"#
+        );
+        assert_eq!(
+            serde_json::json!(IntervalTest { value: 10..20 }).to_string(),
+            r#"{"value":"[10,20)"}"#
+        );
+    }
+}
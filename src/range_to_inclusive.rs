@@ -0,0 +1,107 @@
+//! A range with no start, represented as a pair with a null start (e.g. `[null, 5]`).
+
+use crate::adapter::{Deserializable, DeserializeAdapter, Plain, Serializable, SerializeAdapter};
+use serde::{
+    de::{Deserialize, Deserializer, IgnoredAny, Unexpected},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+use std::marker::PhantomData;
+use std::ops::RangeToInclusive;
+
+const EXPECTED: &str = "range with null start";
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<RangeToInclusive<T>, D::Error> {
+    With::<Plain>::deserialize(deserializer)
+}
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    value: &RangeToInclusive<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    With::<Plain>::serialize(value, serializer)
+}
+
+/// A range whose end is deserialized and serialized with the given adapter.
+pub struct With<A> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> With<A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RangeToInclusive<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        let (start, end): (Option<IgnoredAny>, Deserializable<A, T>) =
+            Deserialize::deserialize(deserializer)?;
+
+        if start.is_some() {
+            Err(serde::de::Error::invalid_value(
+                Unexpected::Other("bounded start"),
+                &EXPECTED,
+            ))
+        } else {
+            Ok(..=end.value)
+        }
+    }
+
+    pub fn serialize<T, S: Serializer>(
+        value: &RangeToInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&None::<()>)?;
+        tuple.serialize_element(&Serializable::<A, T>::new(&value.end))?;
+        tuple.end()
+    }
+}
+
+impl<'de, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, RangeToInclusive<T>>
+    for With<A>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<RangeToInclusive<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<A: SerializeAdapter<T>, T> SerializeAdapter<RangeToInclusive<T>> for With<A> {
+    fn serialize<S: Serializer>(
+        value: &RangeToInclusive<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeToInclusive;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::range_to_inclusive")]
+        value: RangeToInclusive<u32>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = serde_json::from_str::<Test>(r#"{"value":[null,5]}"#).unwrap();
+
+        assert_eq!(value.value, ..=5);
+        assert_eq!(
+            serde_json::json!(value).to_string(),
+            r#"{"value":[null,5]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_bounded_start() {
+        assert!(serde_json::from_str::<Test>(r#"{"value":[3,5]}"#).is_err());
+    }
+}
@@ -38,6 +38,7 @@ pub fn serialize<S: Serializer>(value: &Ratio<i64>, serializer: S) -> Result<S::
 }
 
 #[cfg(test)]
+#[allow(clippy::unreadable_literal)]
 mod tests {
     use crate::ratio_i64;
     use num_rational::Ratio;
@@ -0,0 +1,122 @@
+//! Deserialize either a number or a decimal string into an exact ratio.
+//!
+//! Serialization always produces a decimal string.
+
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+const EXPECTED: &str = "number or decimal string";
+
+// As in `integer_or_integer_str`, non-string values are printed to strings and then parsed. Note
+// that `f64` values are printed using their shortest round-tripping representation, so a number
+// like `0.1` is parsed as exactly one tenth.
+pub fn deserialize<'de, T: Clone + Integer + FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Ratio<T>, D::Error> {
+    struct RatioOrRatioStrVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: Clone + Integer + FromStr> Visitor<'de> for RatioOrRatioStrVisitor<T> {
+        type Value = Ratio<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(v)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(v)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(&v.to_string())
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Unsigned(v), &EXPECTED))
+        }
+
+        fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(&v.to_string()).ok_or_else(|| {
+                serde::de::Error::invalid_value(Unexpected::Other("u128"), &EXPECTED)
+            })
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(&v.to_string())
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Signed(v), &EXPECTED))
+        }
+
+        fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(&v.to_string()).ok_or_else(|| {
+                serde::de::Error::invalid_value(Unexpected::Other("i128"), &EXPECTED)
+            })
+        }
+
+        fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            super::ratio_str::parse_decimal(&v.to_string())
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Float(v), &EXPECTED))
+        }
+    }
+
+    deserializer.deserialize_any(RatioOrRatioStrVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: Clone + Integer + CheckedMul + Display, S: Serializer>(
+    value: &Ratio<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    super::ratio_str::serialize(value, serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ratio_or_ratio_str;
+    use num_rational::Ratio;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "ratio_or_ratio_str")]
+        foo: Ratio<i64>,
+        #[serde(with = "ratio_or_ratio_str")]
+        bar: Ratio<i64>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let from_numbers =
+            serde_json::from_str::<Test>(r#"{ "foo": -0.1372897, "bar": 132791823 }"#).unwrap();
+        let from_strs =
+            serde_json::from_str::<Test>(r#"{ "foo": "-0.1372897", "bar": "132791823" }"#).unwrap();
+
+        assert_eq!(from_numbers.foo, Ratio::new(-1_372_897, 10_000_000));
+        assert_eq!(from_numbers.bar, Ratio::new(132_791_823, 1));
+        assert_eq!(from_numbers, from_strs);
+    }
+
+    #[test]
+    fn serialize_json() {
+        let value = Test {
+            foo: Ratio::new(-1_372_897, 10_000_000),
+            bar: Ratio::new(132_791_823, 1),
+        };
+
+        assert_eq!(
+            serde_json::json!(value).to_string(),
+            r#"{"bar":"132791823","foo":"-0.1372897"}"#
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Deserialize a decimal string into an exact ratio (and the reverse).
+
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::CheckedMul;
+use serde::{
+    de::{Deserializer, Unexpected, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+const EXPECTED: &str = "decimal string";
+
+pub fn deserialize<'de, T: Clone + Integer + FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Ratio<T>, D::Error> {
+    struct RatioStrVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, T: Clone + Integer + FromStr> Visitor<'de> for RatioStrVisitor<T> {
+        type Value = Ratio<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            parse_decimal(v)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            parse_decimal(v)
+                .ok_or_else(|| serde::de::Error::invalid_value(Unexpected::Str(v), &EXPECTED))
+        }
+    }
+
+    deserializer.deserialize_str(RatioStrVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: Clone + Integer + CheckedMul + Display, S: Serializer>(
+    value: &Ratio<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let text = format_decimal(value).ok_or_else(|| {
+        serde::ser::Error::custom("ratio cannot be represented as a finite decimal string")
+    })?;
+
+    serializer.serialize_str(&text)
+}
+
+/// Parse a plain decimal string (an optional sign, digits, and an optional fractional part).
+///
+/// Returns `None` if the string is not a decimal or if either the numerator or the power of ten
+/// used as the denominator cannot be represented in the target type.
+pub(crate) fn parse_decimal<T: Clone + Integer + FromStr>(text: &str) -> Option<Ratio<T>> {
+    let (sign, unsigned) = text.strip_prefix('-').map_or_else(
+        || ("", text.strip_prefix('+').unwrap_or(text)),
+        |rest| ("-", rest),
+    );
+
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((_, "")) => return None,
+        Some((integer, fraction)) => (integer, fraction),
+        None => (unsigned, ""),
+    };
+
+    if integer.is_empty()
+        || !integer.bytes().all(|byte| byte.is_ascii_digit())
+        || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let numerator = format!("{sign}{integer}{fraction}").parse::<T>().ok()?;
+    let denominator = format!("1{}", "0".repeat(fraction.len()))
+        .parse::<T>()
+        .ok()?;
+
+    Some(Ratio::new(numerator, denominator))
+}
+
+/// Format a ratio as an exact decimal string.
+///
+/// Returns `None` if the ratio has no finite decimal expansion (i.e. its reduced denominator has
+/// prime factors other than two and five), or if scaling the numerator overflows.
+pub(crate) fn format_decimal<T: Clone + Integer + CheckedMul + Display>(
+    value: &Ratio<T>,
+) -> Option<String> {
+    let value = value.reduced();
+    let two = T::one() + T::one();
+    let five = two.clone() + two.clone() + T::one();
+
+    let mut rest = value.denom().clone();
+    let mut twos = 0usize;
+    let mut fives = 0usize;
+
+    while rest.is_multiple_of(&two) {
+        rest = rest / two.clone();
+        twos += 1;
+    }
+
+    while rest.is_multiple_of(&five) {
+        rest = rest / five.clone();
+        fives += 1;
+    }
+
+    if !rest.is_one() {
+        return None;
+    }
+
+    let places = twos.max(fives);
+    let mut multiplier = T::one();
+
+    for _ in twos..places {
+        multiplier = multiplier.checked_mul(&two)?;
+    }
+
+    for _ in fives..places {
+        multiplier = multiplier.checked_mul(&five)?;
+    }
+
+    let digits = value.numer().checked_mul(&multiplier)?.to_string();
+    let (sign, digits) = digits
+        .strip_prefix('-')
+        .map_or(("", digits.as_str()), |digits| ("-", digits));
+
+    if places == 0 {
+        Some(format!("{sign}{digits}"))
+    } else {
+        let digits = format!("{digits:0>width$}", width = places + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - places);
+
+        Some(format!("{sign}{integer}.{fraction}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ratio_str;
+    use num_rational::Ratio;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "ratio_str")]
+        foo: Ratio<i64>,
+        #[serde(with = "ratio_str")]
+        bar: Ratio<u64>,
+    }
+
+    #[test]
+    fn parse_json() {
+        let test =
+            serde_json::from_str::<Test>(r#"{ "foo": "-0.1372897", "bar": "132791823" }"#).unwrap();
+
+        assert_eq!(test.foo, Ratio::new(-1_372_897, 10_000_000));
+        assert_eq!(test.bar, Ratio::new(132_791_823, 1));
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let value = Test {
+            foo: Ratio::new(-1_372_897, 10_000_000),
+            bar: Ratio::new(1, 8),
+        };
+
+        let serialized = serde_json::json!(value).to_string();
+        let deserialized = serde_json::from_str::<Test>(&serialized).unwrap();
+
+        assert_eq!(serialized, r#"{"bar":"0.125","foo":"-0.1372897"}"#);
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{ "foo": "1.", "bar": "1" }"#,
+            r#"{ "foo": ".5", "bar": "1" }"#,
+            r#"{ "foo": "1e5", "bar": "1" }"#,
+            r#"{ "foo": " 1", "bar": "1" }"#,
+            r#"{ "foo": "1", "bar": "-1" }"#,
+            r#"{ "foo": "0.00000000000000000001", "bar": "1" }"#,
+            r#"{ "foo": 1, "bar": "1" }"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn format_decimal() {
+        assert_eq!(
+            ratio_str::format_decimal(&Ratio::new(-1i64, 40)),
+            Some("-0.025".to_string())
+        );
+        assert_eq!(
+            ratio_str::format_decimal(&Ratio::new(-30i64, 4)),
+            Some("-7.5".to_string())
+        );
+        assert_eq!(ratio_str::format_decimal(&Ratio::new(1i64, 3)), None);
+    }
+
+    #[test]
+    fn serialize_non_terminating() {
+        let value = Test {
+            foo: Ratio::new(1, 3),
+            bar: Ratio::new(1, 1),
+        };
+
+        assert!(serde_json::to_string(&value).is_err());
+    }
+}
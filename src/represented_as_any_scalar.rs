@@ -0,0 +1,211 @@
+//! Values represented as strings using their `FromStr` and `Display` instances, where the input may
+//! also be a number or boolean (e.g. a version that is sometimes `1` and sometimes `"1.0"`).
+//!
+//! Non-string scalars are formatted and then parsed with `FromStr`, so `FromStr` sees `"1"` for the
+//! integer `1` and `"true"` for `true`. Floating-point numbers are formatted with a decimal point or
+//! exponent (e.g. `1.0` as `"1.0"`). Values are always serialized as strings.
+
+use serde::{
+    de::{Deserializer, Visitor},
+    ser::Serializer,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+const EXPECTED: &str = "string, number, or boolean representation";
+
+// As in `integer_or_integer_str`, non-string values are printed and then parsed, which has a
+// performance cost.
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>
+where
+    T::Err: Display,
+{
+    struct AnyScalarVisitor<T> {
+        _target: PhantomData<T>,
+    }
+
+    impl<T: FromStr> Visitor<'_> for AnyScalarVisitor<T>
+    where
+        T::Err: Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse::<Self::Value>().map_err(serde::de::Error::custom)
+        }
+
+        fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Self::Value, E> {
+            self.visit_str(&v.to_string())
+        }
+
+        fn visit_char<E: serde::de::Error>(self, v: char) -> Result<Self::Value, E> {
+            self.visit_str(v.encode_utf8(&mut [0; 4]))
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            self.visit_str(&v.to_string())
+        }
+
+        fn visit_u128<E: serde::de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            self.visit_str(&v.to_string())
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            self.visit_str(&v.to_string())
+        }
+
+        fn visit_i128<E: serde::de::Error>(self, v: i128) -> Result<Self::Value, E> {
+            self.visit_str(&v.to_string())
+        }
+
+        // The `Debug` representation always includes a decimal point or exponent.
+        fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            self.visit_str(&format!("{v:?}"))
+        }
+    }
+
+    deserializer.deserialize_any(AnyScalarVisitor::<T> {
+        _target: PhantomData,
+    })
+}
+
+pub fn serialize<T: Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{adapter::RepresentedAsAnyScalar, array::Array};
+    use std::str::FromStr;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Version {
+        major: u32,
+        minor: u32,
+    }
+
+    impl FromStr for Version {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (major, minor) = s.split_once('.').unwrap_or((s, "0"));
+
+            match (major.parse(), minor.parse()) {
+                (Ok(major), Ok(minor)) => Ok(Self { major, minor }),
+                _ => Err(format!("invalid version: {s}")),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Version {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}.{}", self.major, self.minor)
+        }
+    }
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::represented_as_any_scalar")]
+        version: Version,
+        #[serde(with = "crate::represented_as_any_scalar")]
+        enabled: bool,
+        #[serde(with = "crate::represented_as_any_scalar")]
+        label: String,
+        #[serde(with = "Array::<RepresentedAsAnyScalar>", default)]
+        history: Vec<Version>,
+    }
+
+    #[test]
+    fn parse_json() {
+        for (json, major, minor, label) in [
+            (r#"{"version":1,"enabled":true,"label":-5}"#, 1, 0, "-5"),
+            (
+                r#"{"version":"2.3","enabled":"true","label":"a"}"#,
+                2,
+                3,
+                "a",
+            ),
+            (r#"{"version":1.0,"enabled":true,"label":1.5}"#, 1, 0, "1.5"),
+            (
+                r#"{"version":4.25,"enabled":true,"label":false}"#,
+                4,
+                25,
+                "false",
+            ),
+        ] {
+            let test = serde_json::from_str::<Test>(json).unwrap();
+
+            assert_eq!(
+                test,
+                Test {
+                    version: Version { major, minor },
+                    enabled: true,
+                    label: label.to_string(),
+                    history: vec![],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_json() {
+        let test = Test {
+            version: Version { major: 1, minor: 0 },
+            enabled: false,
+            label: "x".to_string(),
+            history: vec![Version { major: 0, minor: 9 }],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"version":"1.0","enabled":"false","label":"x","history":["0.9"]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_array() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"version":1,"enabled":true,"label":"","history":[0,"0.5",0.75]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test.history,
+            vec![
+                Version { major: 0, minor: 0 },
+                Version { major: 0, minor: 5 },
+                Version {
+                    major: 0,
+                    minor: 75
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"version":-1,"enabled":true,"label":""}"#,
+                "invalid version: -1",
+            ),
+            (
+                r#"{"version":null,"enabled":true,"label":""}"#,
+                "invalid type: null, expected string, number, or boolean representation",
+            ),
+            (
+                r#"{"version":[1],"enabled":true,"label":""}"#,
+                "invalid type: sequence, expected string, number, or boolean representation",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
@@ -46,3 +46,66 @@ pub fn serialize<T: std::fmt::Display, S: Serializer>(
 ) -> Result<S::Ok, S::Error> {
     serializer.serialize_str(&value.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "crate::represented_as_str")]
+        address: IpAddr,
+        #[serde(with = "crate::optional_represented_as_str")]
+        gateway: Option<Ipv4Addr>,
+        #[serde(with = "crate::represented_as_str_array")]
+        peers: BTreeSet<IpAddr>,
+        #[serde(with = "crate::optional_represented_as_str_array")]
+        blocked: Option<Vec<Ipv6Addr>>,
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let json = r#"{"address":"10.0.0.2","gateway":null,"peers":["::1","10.0.0.1"],"blocked":["fe80::1"]}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                address: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+                gateway: None,
+                peers: BTreeSet::from([
+                    IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+                    IpAddr::V6(Ipv6Addr::LOCALHOST),
+                ]),
+                blocked: Some(vec![Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"address":"10.0.0.2","gateway":null,"peers":["10.0.0.1","::1"],"blocked":["fe80::1"]}"#
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for (json, message) in [
+            (
+                r#"{"address":"::","gateway":"::1","peers":[],"blocked":null}"#,
+                "invalid IPv4 address syntax",
+            ),
+            (
+                r#"{"address":"::","gateway":null,"peers":["::1","x"],"blocked":null}"#,
+                "error at element 1: invalid IP address syntax",
+            ),
+            (
+                r#"{"address":"::","gateway":null,"peers":[],"blocked":["1.1.1.1"]}"#,
+                "error at element 0: invalid IPv6 address syntax",
+            ),
+        ] {
+            let error = serde_json::from_str::<Test>(json).unwrap_err();
+
+            assert!(error.to_string().starts_with(message), "{error}");
+        }
+    }
+}
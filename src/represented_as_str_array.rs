@@ -0,0 +1,27 @@
+//! Deserialize an array of strings into a collection of values using their `FromStr` instance (and
+//! the reverse).
+
+use crate::{adapter::RepresentedAsStr, array::Array};
+use serde::{de::Deserializer, ser::Serializer};
+use std::fmt::Display;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+pub fn deserialize<'de, E: FromStr, T: FromIterator<E>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error>
+where
+    E::Err: Display,
+{
+    Array::<RepresentedAsStr>::deserialize(deserializer)
+}
+
+pub fn serialize<'a, E: Display + 'a, T: 'a, S: Serializer>(
+    values: &'a T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a E>,
+{
+    Array::<RepresentedAsStr>::serialize(values, serializer)
+}
@@ -0,0 +1,493 @@
+//! Optional values where a designated sentinel value (e.g. `-1`, `0`, `""` or `"N/A"`) indicates
+//! absence.
+//!
+//! [`SentinelAsNone`] is parameterized by a [`Sentinel`] and an element adapter for the values that
+//! are present. Integer sentinels are provided by [`Int`], a few common string sentinels are
+//! provided here, and others can be declared with [`str_sentinel!`](crate::str_sentinel):
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use serde_field_attributes::{adapter, sentinel::{EmptyStr, Int, SentinelAsNone}};
+//!
+//! serde_field_attributes::str_sentinel!(pub Unknown = "unknown");
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct Record {
+//!     #[serde(with = "SentinelAsNone::<Int<0>>")]
+//!     count: Option<u32>,
+//!     #[serde(with = "SentinelAsNone::<EmptyStr>")]
+//!     name: Option<String>,
+//!     #[serde(with = "SentinelAsNone::<Unknown, adapter::TimestampStr>")]
+//!     updated: Option<DateTime<Utc>>,
+//! }
+//! ```
+//!
+//! Serialization writes the sentinel for `None`. Deserialization relies on `deserialize_any`, so
+//! these adapters only work with self-describing formats.
+
+use crate::adapter::{DeserializeAdapter, Plain, SerializeAdapter};
+use serde::{
+    de::{
+        Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+        value::{
+            BorrowedBytesDeserializer, BorrowedStrDeserializer, EnumAccessDeserializer,
+            MapAccessDeserializer, SeqAccessDeserializer,
+        },
+    },
+    ser::Serializer,
+};
+use std::marker::PhantomData;
+
+/// A value that indicates absence.
+pub trait Sentinel {
+    #[must_use]
+    fn matches_integer(_value: i128) -> bool {
+        false
+    }
+
+    #[must_use]
+    fn matches_str(_value: &str) -> bool {
+        false
+    }
+
+    #[must_use]
+    fn matches_null() -> bool {
+        false
+    }
+
+    fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Map blank strings (and `null`) to `None` for any string-based adapter (e.g.
+/// `BlankAsNone::<adapter::IntegerStr>`).
+///
+/// This relies on `deserialize_any`, so it doesn't work with non-self-describing formats such as
+/// `bincode`.
+pub type BlankAsNone<A = Plain> = SentinelAsNone<Blank, A>;
+
+/// An integer sentinel (e.g. `Int<-1>`).
+pub struct Int<const N: i64>;
+
+impl<const N: i64> Sentinel for Int<N> {
+    fn matches_integer(value: i128) -> bool {
+        value == i128::from(N)
+    }
+
+    fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(N)
+    }
+}
+
+// Used by `str_sentinel!`, so that callers don't need to depend on `serde` directly.
+#[doc(hidden)]
+pub use serde::Serializer as __Serializer;
+
+/// Declare a string sentinel type.
+///
+/// ```
+/// serde_field_attributes::str_sentinel!(
+///     /// `"none"`
+///     pub NoneStr = "none"
+/// );
+/// ```
+#[macro_export]
+macro_rules! str_sentinel {
+    ($(#[$meta:meta])* $vis:vis $name:ident = $value:literal) => {
+        $(#[$meta])*
+        $vis struct $name;
+
+        impl $crate::sentinel::Sentinel for $name {
+            fn matches_str(value: &str) -> bool {
+                value == $value
+            }
+
+            fn serialize<S: $crate::sentinel::__Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($value)
+            }
+        }
+    };
+}
+
+/// Declare `blank` and `blank::empty_str` modules for an `optional_*` module, with the given
+/// adapter for the values that are present.
+macro_rules! blank_modules {
+    ($adapter:ty) => {
+        /// Treat empty and whitespace-only strings as `None`.
+        ///
+        /// This relies on `deserialize_any`, so it doesn't work with non-self-describing formats
+        /// such as `bincode`.
+        pub mod blank {
+            use serde::de::Deserializer;
+            use $crate::adapter::DeserializeAdapter;
+            use $crate::sentinel::BlankAsNone;
+
+            pub use super::serialize;
+
+            pub fn deserialize<'de, T, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<T>, D::Error>
+            where
+                $adapter: DeserializeAdapter<'de, T>,
+            {
+                BlankAsNone::<$adapter>::deserialize(deserializer)
+            }
+
+            /// Serialize `None` as `""` instead of `null`.
+            pub mod empty_str {
+                use serde::ser::Serializer;
+                use $crate::adapter::SerializeAdapter;
+                use $crate::sentinel::{BlankEmptyStr, SentinelAsNone};
+
+                pub use super::deserialize;
+
+                pub fn serialize<T, S: Serializer>(
+                    value: &Option<T>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error>
+                where
+                    $adapter: SerializeAdapter<T>,
+                {
+                    SentinelAsNone::<BlankEmptyStr, $adapter>::serialize(value, serializer)
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use blank_modules;
+
+str_sentinel!(
+    /// `""`
+    pub EmptyStr = ""
+);
+
+str_sentinel!(
+    /// `"N/A"`
+    pub NotAvailable = "N/A"
+);
+
+str_sentinel!(
+    /// `"null"`
+    pub NullStr = "null"
+);
+
+/// Empty and whitespace-only strings, as well as `null`. `None` is serialized as `null`.
+///
+/// To omit the field instead, use `#[serde(default, skip_serializing_if = "Option::is_none")]`.
+pub struct Blank;
+
+impl Sentinel for Blank {
+    fn matches_str(value: &str) -> bool {
+        value.trim().is_empty()
+    }
+
+    fn matches_null() -> bool {
+        true
+    }
+
+    fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_none()
+    }
+}
+
+/// Like [`Blank`], but `None` is serialized as `""`.
+pub struct BlankEmptyStr;
+
+impl Sentinel for BlankEmptyStr {
+    fn matches_str(value: &str) -> bool {
+        Blank::matches_str(value)
+    }
+
+    fn matches_null() -> bool {
+        true
+    }
+
+    fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("")
+    }
+}
+
+pub struct SentinelAsNone<S, A = Plain> {
+    _sentinel: PhantomData<S>,
+    _adapter: PhantomData<A>,
+}
+
+impl<S: Sentinel, A> SentinelAsNone<S, A> {
+    pub fn deserialize<'de, T, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        A: DeserializeAdapter<'de, T>,
+    {
+        deserializer.deserialize_any(SentinelVisitor::<S, A, T> {
+            _sentinel: PhantomData,
+            _adapter: PhantomData,
+            _target: PhantomData,
+        })
+    }
+
+    pub fn serialize<T, Ser: Serializer>(
+        value: &Option<T>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        A: SerializeAdapter<T>,
+    {
+        match value {
+            Some(value) => A::serialize(value, serializer),
+            None => S::serialize(serializer),
+        }
+    }
+}
+
+impl<'de, S: Sentinel, A: DeserializeAdapter<'de, T>, T> DeserializeAdapter<'de, Option<T>>
+    for SentinelAsNone<S, A>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<S: Sentinel, A: SerializeAdapter<T>, T> SerializeAdapter<Option<T>> for SentinelAsNone<S, A> {
+    fn serialize<Ser: Serializer>(
+        value: &Option<T>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error> {
+        Self::serialize(value, serializer)
+    }
+}
+
+struct SentinelVisitor<S, A, T> {
+    _sentinel: PhantomData<S>,
+    _adapter: PhantomData<A>,
+    _target: PhantomData<T>,
+}
+
+macro_rules! visit_integer {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                if i128::try_from(v).is_ok_and(S::matches_integer) {
+                    Ok(None)
+                } else {
+                    A::deserialize(IntoDeserializer::<'de, E>::into_deserializer(v)).map(Some)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! visit_forward {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                A::deserialize(IntoDeserializer::<'de, E>::into_deserializer(v)).map(Some)
+            }
+        )*
+    };
+}
+
+impl<'de, S: Sentinel, A: DeserializeAdapter<'de, T>, T> Visitor<'de> for SentinelVisitor<S, A, T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("value or sentinel")
+    }
+
+    visit_integer!(
+        visit_i8: i8, visit_i16: i16, visit_i32: i32, visit_i64: i64, visit_i128: i128,
+        visit_u8: u8, visit_u16: u16, visit_u32: u32, visit_u64: u64, visit_u128: u128
+    );
+
+    visit_forward!(visit_bool: bool, visit_f32: f32, visit_f64: f64, visit_char: char);
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        if S::matches_str(v) {
+            Ok(None)
+        } else {
+            A::deserialize(IntoDeserializer::<'de, E>::into_deserializer(v)).map(Some)
+        }
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        if S::matches_str(v) {
+            Ok(None)
+        } else {
+            A::deserialize(BorrowedStrDeserializer::new(v)).map(Some)
+        }
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+        if S::matches_str(&v) {
+            Ok(None)
+        } else {
+            A::deserialize(IntoDeserializer::<'de, E>::into_deserializer(v)).map(Some)
+        }
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        A::deserialize(IntoDeserializer::<'de, E>::into_deserializer(v)).map(Some)
+    }
+
+    fn visit_borrowed_bytes<E: serde::de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        A::deserialize(BorrowedBytesDeserializer::new(v)).map(Some)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        if S::matches_null() {
+            Ok(None)
+        } else {
+            A::deserialize(IntoDeserializer::<'de, E>::into_deserializer(())).map(Some)
+        }
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        A::deserialize(deserializer).map(Some)
+    }
+
+    fn visit_seq<V: SeqAccess<'de>>(self, seq: V) -> Result<Self::Value, V::Error> {
+        A::deserialize(SeqAccessDeserializer::new(seq)).map(Some)
+    }
+
+    fn visit_map<V: MapAccess<'de>>(self, map: V) -> Result<Self::Value, V::Error> {
+        A::deserialize(MapAccessDeserializer::new(map)).map(Some)
+    }
+
+    fn visit_enum<V: EnumAccess<'de>>(self, data: V) -> Result<Self::Value, V::Error> {
+        A::deserialize(EnumAccessDeserializer::new(data)).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlankAsNone, BlankEmptyStr, EmptyStr, Int, NotAvailable, NullStr, SentinelAsNone};
+    use crate::adapter::{IntegerStr, TimestampStr};
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "SentinelAsNone::<Int<0>>")]
+        count: Option<u32>,
+        #[serde(with = "SentinelAsNone::<Int<-1>>")]
+        offset: Option<i64>,
+        #[serde(with = "SentinelAsNone::<EmptyStr>")]
+        name: Option<String>,
+        #[serde(with = "SentinelAsNone::<NotAvailable, IntegerStr>")]
+        id: Option<u64>,
+        #[serde(with = "SentinelAsNone::<NullStr, TimestampStr>")]
+        updated: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn parse_json_present() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"count":3,"offset":-2,"name":"foo","id":"42","updated":"1609459200"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                count: Some(3),
+                offset: Some(-2),
+                name: Some("foo".to_string()),
+                id: Some(42),
+                updated: Some(Utc.timestamp_opt(1_609_459_200, 0).unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip_json_absent() {
+        let json = r#"{"count":0,"offset":-1,"name":"","id":"N/A","updated":"null"}"#;
+        let test = serde_json::from_str::<Test>(json).unwrap();
+
+        assert_eq!(
+            test,
+            Test {
+                count: None,
+                offset: None,
+                name: None,
+                id: None,
+                updated: None,
+            }
+        );
+        assert_eq!(serde_json::to_string(&test).unwrap(), json);
+    }
+
+    #[test]
+    fn parse_json_blank() {
+        #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Blanks {
+            #[serde(with = "BlankAsNone::<IntegerStr>")]
+            id: Option<u64>,
+            #[serde(with = "SentinelAsNone::<BlankEmptyStr>")]
+            name: Option<String>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[serde(with = "BlankAsNone::<TimestampStr>")]
+            updated: Option<DateTime<Utc>>,
+            #[serde(with = "crate::optional_hex_str::blank")]
+            hex: Option<u32>,
+            #[serde(with = "crate::optional_delimited_str::blank::empty_str")]
+            tags: Option<Vec<u8>>,
+        }
+
+        for json in [
+            r#"{"id":"","name":"  ","updated":null,"hex":"","tags":" "}"#,
+            r#"{"id":null,"name":null,"updated":" ","hex":null,"tags":null}"#,
+            r#"{"id":" ","name":"","hex":" ","tags":""}"#,
+        ] {
+            let blanks = serde_json::from_str::<Blanks>(json).unwrap();
+
+            assert_eq!(
+                blanks,
+                Blanks {
+                    id: None,
+                    name: None,
+                    updated: None,
+                    hex: None,
+                    tags: None,
+                }
+            );
+            assert_eq!(
+                serde_json::to_string(&blanks).unwrap(),
+                r#"{"id":null,"name":"","hex":null,"tags":""}"#
+            );
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Blanks>(r#"{"id":"1","name":"","hex":"0xff","tags":"1,2"}"#)
+                .unwrap(),
+            Blanks {
+                id: Some(1),
+                name: None,
+                updated: None,
+                hex: Some(255),
+                tags: Some(vec![1, 2]),
+            }
+        );
+        assert!(
+            serde_json::from_str::<Blanks>(r#"{"id":" 1","name":"","hex":"","tags":""}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        for json in [
+            r#"{"count":-1,"offset":-1,"name":"","id":"N/A","updated":"null"}"#,
+            r#"{"count":0,"offset":-1,"name":"","id":"n/a","updated":"null"}"#,
+            r#"{"count":0,"offset":-1,"name":null,"id":"N/A","updated":"null"}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+}
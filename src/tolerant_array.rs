@@ -0,0 +1,469 @@
+//! Deserialize an array with an element adapter, tolerating elements that the adapter rejects
+//! (and the reverse).
+//!
+//! Invalid elements can be skipped ([`SkipInvalid`]), replaced with the default value
+//! ([`DefaultInvalid`]), or collected alongside the valid elements as [`ElementError`]s
+//! ([`CollectInvalid`]), which is useful for logging what was skipped:
+//!
+//! ```
+//! use serde_field_attributes::{adapter, array::ElementError, tolerant_array::CollectInvalid};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Scraped {
+//!     #[serde(deserialize_with = "CollectInvalid::<adapter::IntegerStr>::deserialize")]
+//!     ids: (Vec<u64>, Vec<ElementError>),
+//! }
+//!
+//! let scraped: Scraped = serde_json::from_str(r#"{"ids":["1","x","3"]}"#).unwrap();
+//!
+//! assert_eq!(scraped.ids.0, vec![1, 3]);
+//! assert_eq!(scraped.ids.1[0].index, 1);
+//! ```
+//!
+//! Only errors returned by the element adapter are tolerated. Errors in the structure of the
+//! array itself (e.g. a syntax error) still fail the whole array, and so do element errors that
+//! leave the input in the middle of a value, since there is no way to resume reading the array
+//! after them. For example, `serde_json` doesn't consume a nested array or object when the
+//! adapter expects a string.
+
+use crate::{
+    adapter::{DeserializeAdapter, Plain, SerializeAdapter},
+    array::{Array, ElementError, preallocation_hint},
+};
+use serde::{
+    de::{Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess, Visitor},
+    ser::Serializer,
+};
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+
+const EXPECTED: &str = "array";
+
+/// Drop elements that the adapter rejects.
+pub struct SkipInvalid<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> SkipInvalid<A> {
+    pub fn deserialize<'de, E, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        A: DeserializeAdapter<'de, E>,
+    {
+        deserialize_tolerant::<A, E, T, D, _>(deserializer, |_| None)
+    }
+
+    pub fn serialize<'a, E: 'a, T: 'a, S: Serializer>(
+        values: &'a T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+        A: SerializeAdapter<E>,
+    {
+        Array::<A>::serialize(values, serializer)
+    }
+}
+
+/// Replace elements that the adapter rejects with the default value.
+pub struct DefaultInvalid<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> DefaultInvalid<A> {
+    pub fn deserialize<'de, E: Default, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error>
+    where
+        A: DeserializeAdapter<'de, E>,
+    {
+        deserialize_tolerant::<A, E, T, D, _>(deserializer, |_| Some(E::default()))
+    }
+
+    pub fn serialize<'a, E: 'a, T: 'a, S: Serializer>(
+        values: &'a T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+        A: SerializeAdapter<E>,
+    {
+        Array::<A>::serialize(values, serializer)
+    }
+}
+
+/// Deserialize into the valid elements and the errors for the elements that the adapter rejects.
+///
+/// Only the valid elements are serialized.
+pub struct CollectInvalid<A = Plain> {
+    _adapter: PhantomData<A>,
+}
+
+impl<A> CollectInvalid<A> {
+    pub fn deserialize<'de, E, T: FromIterator<E>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<(T, Vec<ElementError>), D::Error>
+    where
+        A: DeserializeAdapter<'de, E>,
+    {
+        let mut errors = vec![];
+
+        let values = deserialize_tolerant::<A, E, T, D, _>(deserializer, |error| {
+            errors.push(error);
+            None
+        })?;
+
+        Ok((values, errors))
+    }
+
+    pub fn serialize<'a, E: 'a, T: 'a, S: Serializer>(
+        values: &'a (T, Vec<ElementError>),
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        &'a T: IntoIterator<Item = &'a E>,
+        A: SerializeAdapter<E>,
+    {
+        Array::<A>::serialize(&values.0, serializer)
+    }
+}
+
+/// Deserialize an array, passing each element error to the handler, which can provide a
+/// replacement value.
+fn deserialize_tolerant<'de, A, E, T, D, F>(deserializer: D, handle: F) -> Result<T, D::Error>
+where
+    A: DeserializeAdapter<'de, E>,
+    T: FromIterator<E>,
+    D: Deserializer<'de>,
+    F: FnMut(ElementError) -> Option<E>,
+{
+    struct TolerantArrayVisitor<A, E, T, F> {
+        handle: F,
+        _adapter: PhantomData<A>,
+        _element: PhantomData<E>,
+        _target: PhantomData<T>,
+    }
+
+    impl<'de, A, E, T, F> Visitor<'de> for TolerantArrayVisitor<A, E, T, F>
+    where
+        A: DeserializeAdapter<'de, E>,
+        T: FromIterator<E>,
+        F: FnMut(ElementError) -> Option<E>,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str(EXPECTED)
+        }
+
+        fn visit_seq<S: SeqAccess<'de>>(self, seq: S) -> Result<Self::Value, S::Error> {
+            let mut error = OnceCell::new();
+
+            let result = TolerantSeqAccessIterator::<'de, '_, S, A, E, F> {
+                underlying: seq,
+                index: 0,
+                error: &mut error,
+                handle: self.handle,
+                _adapter: PhantomData,
+                _element: PhantomData,
+            }
+            .collect();
+
+            error.take().map_or_else(|| Ok(result), |error| Err(error))
+        }
+    }
+
+    deserializer.deserialize_seq(TolerantArrayVisitor::<A, E, T, F> {
+        handle,
+        _adapter: PhantomData,
+        _element: PhantomData,
+        _target: PhantomData,
+    })
+}
+
+struct TolerantSeqAccessIterator<'de, 'a, S: SeqAccess<'de>, A, E, F> {
+    underlying: S,
+    index: usize,
+    error: &'a mut OnceCell<S::Error>,
+    handle: F,
+    _adapter: PhantomData<A>,
+    _element: PhantomData<E>,
+}
+
+impl<'de, S, A, E, F> Iterator for TolerantSeqAccessIterator<'de, '_, S, A, E, F>
+where
+    S: SeqAccess<'de>,
+    A: DeserializeAdapter<'de, E>,
+    F: FnMut(ElementError) -> Option<E>,
+{
+    type Item = E;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        preallocation_hint::<E>(self.underlying.size_hint())
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.get().is_some() {
+            return None;
+        }
+
+        loop {
+            match self.underlying.next_element::<Attempt<A, E>>() {
+                Ok(Some(attempt)) => {
+                    let index = self.index;
+                    self.index += 1;
+
+                    match attempt.result {
+                        Ok(value) => return Some(value),
+                        Err(message) => {
+                            if let Some(value) = (self.handle)(ElementError { index, message }) {
+                                return Some(value);
+                            }
+                        }
+                    }
+                }
+                Ok(None) => return None,
+                Err(error) => {
+                    // We've just checked whether the cell is initialized.
+                    self.error.set(error).unwrap();
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// The result of deserializing an element with the given adapter, with the error message if it
+/// was rejected.
+struct Attempt<A, E> {
+    result: Result<E, String>,
+    _adapter: PhantomData<A>,
+}
+
+impl<'de, A: DeserializeAdapter<'de, E>, E> Deserialize<'de> for Attempt<A, E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let message = OnceCell::new();
+
+        let result = A::deserialize(MessageCapture {
+            deserializer,
+            message: &message,
+        });
+
+        Ok(Self {
+            // Errors that the deserializer raises itself (e.g. `serde_json` invalid type errors)
+            // only have its message.
+            result: result
+                .map_err(|error| message.into_inner().unwrap_or_else(|| error.to_string())),
+            _adapter: PhantomData,
+        })
+    }
+}
+
+/// A deserializer that records the messages of visitor errors before the underlying deserializer
+/// sees them, since it may add details that depend on the input (e.g. `serde_json` adds the
+/// position).
+struct MessageCapture<'a, D> {
+    deserializer: D,
+    message: &'a OnceCell<String>,
+}
+
+macro_rules! deserialize_capture {
+    ($($method:ident($($arg:ident: $ty:ty),*)),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, D::Error> {
+                self.deserializer.$method($($arg,)* CaptureVisitor {
+                    visitor,
+                    message: self.message,
+                })
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for MessageCapture<'_, D> {
+    type Error = D::Error;
+
+    deserialize_capture!(
+        deserialize_any(), deserialize_bool(), deserialize_i8(), deserialize_i16(),
+        deserialize_i32(), deserialize_i64(), deserialize_i128(), deserialize_u8(),
+        deserialize_u16(), deserialize_u32(), deserialize_u64(), deserialize_u128(),
+        deserialize_f32(), deserialize_f64(), deserialize_char(), deserialize_str(),
+        deserialize_string(), deserialize_bytes(), deserialize_byte_buf(), deserialize_option(),
+        deserialize_unit(), deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str), deserialize_seq(),
+        deserialize_tuple(len: usize), deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(), deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(), deserialize_ignored_any()
+    );
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
+
+/// Calls the visitor with `serde::de::value::Error` as the error type, recording the message.
+struct CaptureVisitor<'a, V> {
+    visitor: V,
+    message: &'a OnceCell<String>,
+}
+
+impl<V> CaptureVisitor<'_, V> {
+    fn capture<T, E: serde::de::Error>(
+        message: &OnceCell<String>,
+        result: Result<T, serde::de::value::Error>,
+    ) -> Result<T, E> {
+        result.map_err(|error| {
+            let error = error.to_string();
+            let result = E::custom(&error);
+
+            // A nested visitor may have already recorded a more specific message.
+            let _ = message.set(error);
+            result
+        })
+    }
+}
+
+macro_rules! visit_capture {
+    ($($method:ident($($arg:ident: $ty:ty)?)),*) => {
+        $(
+            fn $method<E: serde::de::Error>(self $(, $arg: $ty)?) -> Result<Self::Value, E> {
+                Self::capture(self.message, self.visitor.$method($($arg)?))
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CaptureVisitor<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    visit_capture!(
+        visit_bool(v: bool), visit_i8(v: i8), visit_i16(v: i16), visit_i32(v: i32),
+        visit_i64(v: i64), visit_i128(v: i128), visit_u8(v: u8), visit_u16(v: u16),
+        visit_u32(v: u32), visit_u64(v: u64), visit_u128(v: u128), visit_f32(v: f32),
+        visit_f64(v: f64), visit_char(v: char), visit_str(v: &str), visit_borrowed_str(v: &'de str),
+        visit_string(v: String), visit_bytes(v: &[u8]), visit_borrowed_bytes(v: &'de [u8]),
+        visit_byte_buf(v: Vec<u8>), visit_none(), visit_unit()
+    );
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.visitor.visit_some(MessageCapture {
+            deserializer,
+            message: self.message,
+        })
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.visitor.visit_newtype_struct(MessageCapture {
+            deserializer,
+            message: self.message,
+        })
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, seq: S) -> Result<Self::Value, S::Error> {
+        self.visitor.visit_seq(seq)
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, map: M) -> Result<Self::Value, M::Error> {
+        self.visitor.visit_map(map)
+    }
+
+    fn visit_enum<EA: EnumAccess<'de>>(self, data: EA) -> Result<Self::Value, EA::Error> {
+        self.visitor.visit_enum(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CollectInvalid, DefaultInvalid, SkipInvalid};
+    use crate::adapter::{HexStr, IntegerStr, IntegerStrWithReason};
+    use crate::array::ElementError;
+    use serde::de::value::SeqDeserializer;
+    use std::collections::BTreeSet;
+
+    #[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct Test {
+        #[serde(with = "SkipInvalid::<IntegerStr>")]
+        skipped: BTreeSet<u64>,
+        #[serde(with = "DefaultInvalid::<HexStr>")]
+        defaulted: Vec<u8>,
+        #[serde(with = "CollectInvalid::<IntegerStrWithReason>")]
+        collected: (Vec<i32>, Vec<ElementError>),
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let test = serde_json::from_str::<Test>(
+            r#"{"skipped":["2","x",1,"1"],"defaulted":["0x1","0x100","0xf"],"collected":["-1","1.5",null]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(test.skipped, BTreeSet::from([1, 2]));
+        assert_eq!(test.defaulted, vec![1, 0, 15]);
+        assert_eq!(test.collected.0, vec![-1]);
+        assert_eq!(
+            test.collected.1[0],
+            ElementError {
+                index: 1,
+                message: "invalid value: string \"1.5\", expected integer string (invalid digit found in string)".to_string(),
+            }
+        );
+        // `serde_json` raises invalid type errors itself, so they include the position.
+        assert_eq!(test.collected.1[1].index, 2);
+        assert!(
+            test.collected.1[1]
+                .message
+                .starts_with("invalid type: null, expected integer string")
+        );
+        assert_eq!(
+            serde_json::to_string(&test).unwrap(),
+            r#"{"skipped":["1","2"],"defaulted":["0x1","0x0","0xf"],"collected":["-1"]}"#
+        );
+    }
+
+    /// A collection that only records the size hint it was built from.
+    struct SizeHint(usize);
+
+    impl<E> FromIterator<E> for SizeHint {
+        fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+            let iter = iter.into_iter();
+            let hint = iter.size_hint().0;
+
+            iter.for_each(drop);
+            Self(hint)
+        }
+    }
+
+    #[test]
+    fn deserialize_size_hint() {
+        let deserializer =
+            SeqDeserializer::<_, serde::de::value::Error>::new(["1", "x", "3"].into_iter());
+        let SizeHint(hint) =
+            SkipInvalid::<IntegerStr>::deserialize::<u64, _, _>(deserializer).unwrap();
+
+        assert_eq!(hint, 3);
+    }
+
+    #[test]
+    fn parse_json_invalid_structure() {
+        for json in [
+            r#"{"skipped":"1","defaulted":[],"collected":[]}"#,
+            r#"{"skipped":[["1"],"2"],"defaulted":[],"collected":[]}"#,
+            r#"{"skipped":["1" "2"],"defaulted":[],"collected":[]}"#,
+        ] {
+            assert!(serde_json::from_str::<Test>(json).is_err());
+        }
+    }
+}